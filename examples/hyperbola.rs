@@ -21,7 +21,6 @@ use graphics::*;
 use higher_order_core::*;
 use hop::*;
 use camera_controllers::*;
-use vecmath::Matrix4;
 
 fn main() {
     let opengl = OpenGL::V3_2;
@@ -97,7 +96,8 @@ fn main() {
                     }
                 }
 
-                renderer.draw(&window, &mvp, &c, g);
+                let draw_size = window.draw_size();
+                renderer.draw([draw_size.width, draw_size.height], &mvp, &c, g);
             })
         }
 
@@ -105,7 +105,7 @@ fn main() {
             time += args.dt;
         }
 
-        if let Some(_) = e.resize_args() {
+        if e.resize_args().is_some() {
             projection = get_projection(&window);
         }
 
@@ -252,49 +252,3 @@ impl<T: 'static + Copy> Surface for HyperbolaFunc<T> {
         }
     }
 }
-
-/// A crappy 3D point renderer.
-pub struct Renderer {
-    pub points: Vec<Point>,
-}
-
-impl Renderer {
-    pub fn new() -> Renderer {
-        Renderer {
-            points: vec![]
-        }
-    }
-
-    pub fn sample(&mut self, p: &PointFunc<f64>, n: usize) {
-        for i in 0..n {
-            let x = i as f64 / n as f64;
-            self.points.push(p.call(x));
-        }
-    }
-
-    pub fn sample2(&mut self, p: &PointFunc<[f64; 2]>, n: [usize; 2]) {
-        for i in 0..n[0] {
-            for j in 0..n[1] {
-                self.points.push(p.call([i as f64 / n[0] as f64, j as f64 / n[1] as f64]));
-            }
-        }
-    }
-
-    pub fn draw(&self, window: &impl Window, mvp: &Matrix4<f32>, c: &Context, g: &mut impl Graphics) {
-        let rad = 0.01;
-        let draw_size = window.draw_size();
-        let halfw = draw_size.width / 2.0;
-        let tr = c.transform.trans(halfw, draw_size.height / 2.0).scale(halfw, -halfw);
-        for p in &self.points {
-            let p = [p.x as f32, p.y as f32, p.z as f32, 1.0];
-            let p = vecmath::col_mat4_transform(*mvp, p);
-            if p[2] < 0.0 {continue};
-            let p = vecmath::vec4_scale(p, 1.0/p[3]);
-            rectangle(
-                [0.0, 0.0, 0.0, 1.0],
-                [p[0] as f64 - rad, p[1] as f64 - rad, 2.0 * rad, 2.0 * rad],
-                tr, g
-            );
-        }
-    }
-}
@@ -27,7 +27,7 @@ use std::sync::Arc;
 
 fn main() {
     // 2 pi.
-    let tau = 6.283185307179586;
+    let tau = TAU;
     // Define triangle coordinates by continuous indices.
     let p = PointFunc::<f64> {
         x: Arc::new(move |i| (i / 3.0 * tau).cos()),
@@ -48,16 +48,16 @@ fn main() {
 
     let triangle_coords: [Point; 3] = triangle.hmap(&p);
     println!("triangle coords {:?}:", triangle);
-    for i in 0..3 {
-        println!("\t{:?}", triangle_coords[i]);
+    for q in &triangle_coords {
+        println!("\t{:?}", q);
     }
 
     let rotated_triangle: [f64; 3] = triangle.hmap(&rotate);
 
     let rotated_triangle_coords: [Point; 3] = rotated_triangle.hmap(&p);
     println!("rotated triangle coords {:?}:", rotated_triangle);
-    for i in 0..3 {
-        println!("\t{:?}", rotated_triangle_coords[i]);
+    for q in &rotated_triangle_coords {
+        println!("\t{:?}", q);
     }
 
     let xs: [f64; 2] = edge.hmap(&p.x);
@@ -67,16 +67,16 @@ fn main() {
 
     let edge_coords: [Point; 2] = edge.hmap(&p);
     println!("edge coords {:?}:", edge);
-    for i in 0..2 {
-        println!("\t{:?}", edge_coords[i]);
+    for q in &edge_coords {
+        println!("\t{:?}", q);
     }
 
     let rotated_edge: [f64; 2] = edge.hmap(&rotate);
 
     let rotated_edge_coords: [Point; 2] = rotated_edge.hmap(&p);
     println!("rotated edge coords {:?}:", rotated_edge);
-    for i in 0..2 {
-        println!("\t{:?}", rotated_edge_coords[i]);
+    for q in &rotated_edge_coords {
+        println!("\t{:?}", q);
     }
 
     let corner = [[0.0, 1.0], [1.0, 2.0]];
@@ -87,10 +87,10 @@ fn main() {
 
     let corner_coords: [[Point; 2]; 2] = corner.hmap(&p);
     println!("corner coords {:?}:", corner);
-    for i in 0..2 {
+    for row in &corner_coords {
         println!("\t[");
-        for j in 0..2 {
-            println!("\t\t{:?}", corner_coords[i][j]);
+        for q in row {
+            println!("\t\t{:?}", q);
         }
         println!("\t],");
     }
@@ -99,10 +99,10 @@ fn main() {
 
     let rotated_corner_coords: [[Point; 2]; 2] = rotated_corner.hmap(&p);
     println!("rotated corner coords {:?}:", rotated_corner);
-    for i in 0..2 {
+    for row in &rotated_corner_coords {
         println!("\t[");
-        for j in 0..2 {
-            println!("\t\t{:?}", rotated_corner_coords[i][j]);
+        for q in row {
+            println!("\t\t{:?}", q);
         }
         println!("\t],");
     }
@@ -116,16 +116,16 @@ fn main() {
     let in_between_edge: [f64; 2] = (edge, rotated_edge).hpair().hmap(&in_between);
     let in_between_edge_coords: [Point; 2] = in_between_edge.hmap(&p);
     println!("in-between rotated edge coords {:?}:", in_between_edge);
-    for i in 0..2 {
-        println!("\t{:?}", in_between_edge_coords[i]);
+    for q in &in_between_edge_coords {
+        println!("\t{:?}", q);
     }
     let in_between_corner: [[f64; 2]; 2] = (corner, rotated_corner).hpair().hmap(&in_between);
     let in_between_corner_coords: [[Point; 2]; 2] = in_between_corner.hmap(&p);
     println!("in-between rotated corner coords {:?}:", in_between_corner);
-    for i in 0..2 {
+    for row in &in_between_corner_coords {
         println!("\t[");
-        for j in 0..2 {
-            println!("\t\t{:?}", in_between_corner_coords[i][j]);
+        for q in row {
+            println!("\t\t{:?}", q);
         }
         println!("\t],");
     }
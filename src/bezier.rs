@@ -0,0 +1,148 @@
+//! Bezier curves from control points.
+
+use super::*;
+
+/// Creates a bezier curve over `[0, 1]` from a slice of control points.
+///
+/// The degree of the curve is one less than the number of control points.
+pub fn bezier(points: &[Point]) -> PointFunc<f64> {
+    fn coord(cs: Vec<f64>) -> Func<f64, f64> {
+        Arc::new(move |t| {
+            if cs.is_empty() {return 0.0};
+            let mut cs = cs.clone();
+            for n in (1..cs.len()).rev() {
+                for i in 0..n {
+                    cs[i] += (cs[i + 1] - cs[i]) * t;
+                }
+            }
+            cs[0]
+        })
+    }
+    Point {
+        x: coord(points.iter().map(|p| p.x).collect()),
+        y: coord(points.iter().map(|p| p.y).collect()),
+        z: coord(points.iter().map(|p| p.z).collect()),
+    }
+}
+
+/// Returns the control points of the derivative (hodograph) of a bezier curve.
+pub fn hodograph(points: &[Point]) -> Vec<Point> {
+    let n = points.len().saturating_sub(1) as f64;
+    points.windows(2).map(|w| (w[1] - w[0]) * n).collect()
+}
+
+/// Creates the exact derivative of a bezier curve from its control points.
+pub fn bezier_derivative(points: &[Point]) -> PointFunc<f64> {
+    bezier(&hodograph(points))
+}
+
+/// Splits a bezier curve at parameter `t` using De Casteljau's algorithm.
+///
+/// Returns the control points of the curve on `[0, t]` and `[t, 1]`,
+/// each reparameterized to `[0, 1]`.
+pub fn split_bezier(points: &[Point], t: f64) -> (Vec<Point>, Vec<Point>) {
+    let n = points.len();
+    let mut left = Vec::with_capacity(n);
+    let mut right = Vec::with_capacity(n);
+    let mut ps = points.to_vec();
+    for k in (0..n).rev() {
+        left.push(ps[0]);
+        right.push(ps[k]);
+        for i in 0..k {
+            ps[i] = ps[i] + (ps[i + 1] - ps[i]) * t;
+        }
+    }
+    right.reverse();
+    (left, right)
+}
+
+/// Returns control points of an equivalent bezier curve of one higher degree.
+pub fn elevate_degree(points: &[Point]) -> Vec<Point> {
+    let n = points.len();
+    if n == 0 {return vec![]};
+    let mut res = Vec::with_capacity(n + 1);
+    res.push(points[0]);
+    for i in 1..n {
+        let a = i as f64 / n as f64;
+        res.push(points[i - 1] * a + points[i] * (1.0 - a));
+    }
+    res.push(points[n - 1]);
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cubic() -> Vec<Point> {
+        vec![
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(1.0, 2.0, 0.0),
+            Point::new(3.0, 2.0, 1.0),
+            Point::new(4.0, 0.0, 0.0),
+        ]
+    }
+
+    #[test]
+    fn test_bezier() {
+        let points = cubic();
+        let a = bezier(&points);
+        let b = PointFunc::<f64>::constant(points[0]);
+        let c = PointFunc::<f64>::constant(points[1]);
+        let d = PointFunc::<f64>::constant(points[2]);
+        let e = PointFunc::<f64>::constant(points[3]);
+        let t = id();
+        let f = cbez!(&line(&b, &c, &t), &line(&c, &d, &t), &line(&c, &d, &t), &line(&d, &e, &t), &t);
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            assert!(a.call(t).approx_eq(&f.call(t), 1e-12));
+        }
+        assert_eq!(a.call(0.0), points[0]);
+        assert_eq!(a.call(1.0), points[3]);
+    }
+
+    #[test]
+    fn test_bezier_derivative() {
+        let points = cubic();
+        let da = bezier_derivative(&points);
+        let eps = 0.0000001;
+        let db = bezier(&points).diff(eps);
+        for i in 0..10 {
+            let t = i as f64 / 10.0;
+            assert!(da.call(t).approx_eq(&db.call(t), 0.00001));
+        }
+        assert_eq!(da.call(0.0), (points[1] - points[0]) * 3.0);
+        assert_eq!(bezier_derivative(&points[..1]).call(0.5), Point::origin());
+    }
+
+    #[test]
+    fn test_split_bezier() {
+        let points = cubic();
+        let a = bezier(&points);
+        let t = 0.3;
+        let (left, right) = split_bezier(&points, t);
+        assert_eq!(left.len(), 4);
+        assert_eq!(right.len(), 4);
+        let b = bezier(&left);
+        let c = bezier(&right);
+        for i in 0..=10 {
+            let s = i as f64 / 10.0;
+            assert!(b.call(s).approx_eq(&a.call(s * t), 1e-12));
+            assert!(c.call(s).approx_eq(&a.call(t + s * (1.0 - t)), 1e-12));
+        }
+        assert!(split_bezier(&[], 0.5).0.is_empty());
+    }
+
+    #[test]
+    fn test_elevate_degree() {
+        let points = cubic();
+        let elevated = elevate_degree(&points);
+        assert_eq!(elevated.len(), 5);
+        let a = bezier(&points);
+        let b = bezier(&elevated);
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            assert!(a.call(t).approx_eq(&b.call(t), 1e-12));
+        }
+    }
+}
@@ -0,0 +1,508 @@
+//! Dual numbers for exact derivatives.
+//!
+//! A dual number `re + du * ε`, where `ε² = 0`,
+//! carries the derivative of a computation alongside its value.
+//! A jet generalizes this to `ε^(n + 1) = 0`, carrying derivatives up to order `n`.
+
+use super::*;
+
+use std::ops::Neg;
+
+/// Dual number.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Dual {
+    /// Real part.
+    pub re: f64,
+    /// Dual part, the derivative.
+    pub du: f64,
+}
+
+impl Dual {
+    /// Creates a variable, which has derivative one.
+    pub fn var(re: f64) -> Dual {
+        Dual {re, du: 1.0}
+    }
+
+    /// Creates a constant, which has derivative zero.
+    pub fn constant(re: f64) -> Dual {
+        Dual {re, du: 0.0}
+    }
+
+    /// Returns the sine.
+    pub fn sin(self) -> Dual {
+        Dual {re: self.re.sin(), du: self.du * self.re.cos()}
+    }
+
+    /// Returns the cosine.
+    pub fn cos(self) -> Dual {
+        Dual {re: self.re.cos(), du: -self.du * self.re.sin()}
+    }
+
+    /// Returns the exponential.
+    pub fn exp(self) -> Dual {
+        let e = self.re.exp();
+        Dual {re: e, du: self.du * e}
+    }
+
+    /// Returns the natural logarithm.
+    pub fn ln(self) -> Dual {
+        Dual {re: self.re.ln(), du: self.du / self.re}
+    }
+
+    /// Returns the square root.
+    pub fn sqrt(self) -> Dual {
+        let s = self.re.sqrt();
+        Dual {re: s, du: self.du / (2.0 * s)}
+    }
+
+    /// Returns the integer power.
+    pub fn powi(self, n: i32) -> Dual {
+        Dual {re: self.re.powi(n), du: self.du * n as f64 * self.re.powi(n - 1)}
+    }
+}
+
+impl Add for Dual {
+    type Output = Dual;
+    fn add(self, other: Dual) -> Dual {
+        Dual {re: self.re + other.re, du: self.du + other.du}
+    }
+}
+
+impl Add<f64> for Dual {
+    type Output = Dual;
+    fn add(self, other: f64) -> Dual {
+        Dual {re: self.re + other, du: self.du}
+    }
+}
+
+impl Sub for Dual {
+    type Output = Dual;
+    fn sub(self, other: Dual) -> Dual {
+        Dual {re: self.re - other.re, du: self.du - other.du}
+    }
+}
+
+impl Sub<f64> for Dual {
+    type Output = Dual;
+    fn sub(self, other: f64) -> Dual {
+        Dual {re: self.re - other, du: self.du}
+    }
+}
+
+impl Mul for Dual {
+    type Output = Dual;
+    fn mul(self, other: Dual) -> Dual {
+        Dual {
+            re: self.re * other.re,
+            du: self.du * other.re + self.re * other.du,
+        }
+    }
+}
+
+impl Mul<f64> for Dual {
+    type Output = Dual;
+    fn mul(self, other: f64) -> Dual {
+        Dual {re: self.re * other, du: self.du * other}
+    }
+}
+
+impl Div for Dual {
+    type Output = Dual;
+    fn div(self, other: Dual) -> Dual {
+        Dual {
+            re: self.re / other.re,
+            du: (self.du * other.re - self.re * other.du) / (other.re * other.re),
+        }
+    }
+}
+
+impl Div<f64> for Dual {
+    type Output = Dual;
+    fn div(self, other: f64) -> Dual {
+        Dual {re: self.re / other, du: self.du / other}
+    }
+}
+
+impl Neg for Dual {
+    type Output = Dual;
+    fn neg(self) -> Dual {
+        Dual {re: -self.re, du: -self.du}
+    }
+}
+
+/// Truncated Taylor series, a dual number of arbitrary order.
+///
+/// Coefficient `k` is the `k`-th derivative divided by `k!`.
+/// Jets of different lengths combine as if the missing coefficients were zero.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Jet {
+    /// Taylor coefficients, starting with the value.
+    pub coeffs: Vec<f64>,
+}
+
+impl Jet {
+    /// Creates a variable at `t`, carrying derivatives up to `order`.
+    pub fn var(t: f64, order: usize) -> Jet {
+        let mut coeffs = vec![0.0; order + 1];
+        coeffs[0] = t;
+        if order > 0 {coeffs[1] = 1.0};
+        Jet {coeffs}
+    }
+
+    /// Creates a constant.
+    pub fn constant(v: f64) -> Jet {
+        Jet {coeffs: vec![v]}
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> f64 {
+        self.coeffs[0]
+    }
+
+    /// Returns the `k`-th derivative, which is zero beyond the order of the jet.
+    pub fn derivative(&self, k: usize) -> f64 {
+        let factorial: f64 = (1..=k).map(|i| i as f64).product();
+        self.coeffs.get(k).map(|c| c * factorial).unwrap_or(0.0)
+    }
+
+    fn get(&self, k: usize) -> f64 {
+        self.coeffs.get(k).cloned().unwrap_or(0.0)
+    }
+
+    /// Applies a function given its value and a recurrence for the higher coefficients.
+    ///
+    /// The recurrence receives the coefficients computed so far and the index `k`.
+    fn unary<F: Fn(&[f64], usize) -> f64>(&self, value: f64, f: F) -> Jet {
+        let mut coeffs = Vec::with_capacity(self.coeffs.len());
+        coeffs.push(value);
+        for k in 1..self.coeffs.len() {
+            let c = f(&coeffs, k);
+            coeffs.push(c);
+        }
+        Jet {coeffs}
+    }
+
+    /// Returns the sine.
+    pub fn sin(&self) -> Jet {
+        self.sin_cos().0
+    }
+
+    /// Returns the cosine.
+    pub fn cos(&self) -> Jet {
+        self.sin_cos().1
+    }
+
+    /// Returns the sine and cosine, which are computed together.
+    pub fn sin_cos(&self) -> (Jet, Jet) {
+        let n = self.coeffs.len();
+        let a = &self.coeffs;
+        let mut s = vec![a[0].sin()];
+        let mut c = vec![a[0].cos()];
+        for k in 1..n {
+            let mut sk = 0.0;
+            let mut ck = 0.0;
+            for i in 1..=k {
+                sk += i as f64 * a[i] * c[k - i];
+                ck -= i as f64 * a[i] * s[k - i];
+            }
+            s.push(sk / k as f64);
+            c.push(ck / k as f64);
+        }
+        (Jet {coeffs: s}, Jet {coeffs: c})
+    }
+
+    /// Returns the exponential.
+    pub fn exp(&self) -> Jet {
+        let a = &self.coeffs;
+        self.unary(a[0].exp(), |e, k| {
+            (1..=k).map(|i| i as f64 * a[i] * e[k - i]).sum::<f64>() / k as f64
+        })
+    }
+
+    /// Returns the natural logarithm.
+    pub fn ln(&self) -> Jet {
+        let a = &self.coeffs;
+        self.unary(a[0].ln(), |l, k| {
+            let s: f64 = (1..k).map(|i| i as f64 * l[i] * a[k - i]).sum();
+            (a[k] - s / k as f64) / a[0]
+        })
+    }
+
+    /// Returns the square root.
+    pub fn sqrt(&self) -> Jet {
+        let a = &self.coeffs;
+        self.unary(a[0].sqrt(), |r, k| {
+            let s: f64 = (1..k).map(|i| r[i] * r[k - i]).sum();
+            (a[k] - s) / (2.0 * r[0])
+        })
+    }
+
+    /// Returns the integer power.
+    pub fn powi(&self, n: i32) -> Jet {
+        let mut res = Jet::constant(1.0);
+        let mut base = self.clone();
+        let mut e = n.unsigned_abs();
+        while e > 0 {
+            if e & 1 == 1 {res = res * base.clone()};
+            base = base.clone() * base;
+            e >>= 1;
+        }
+        if n < 0 {Jet::constant(1.0) / res} else {res}
+    }
+}
+
+impl Add for Jet {
+    type Output = Jet;
+    fn add(self, other: Jet) -> Jet {
+        let n = self.coeffs.len().max(other.coeffs.len());
+        Jet {coeffs: (0..n).map(|k| self.get(k) + other.get(k)).collect()}
+    }
+}
+
+impl Add<f64> for Jet {
+    type Output = Jet;
+    fn add(mut self, other: f64) -> Jet {
+        self.coeffs[0] += other;
+        self
+    }
+}
+
+impl Sub for Jet {
+    type Output = Jet;
+    fn sub(self, other: Jet) -> Jet {
+        let n = self.coeffs.len().max(other.coeffs.len());
+        Jet {coeffs: (0..n).map(|k| self.get(k) - other.get(k)).collect()}
+    }
+}
+
+impl Sub<f64> for Jet {
+    type Output = Jet;
+    fn sub(mut self, other: f64) -> Jet {
+        self.coeffs[0] -= other;
+        self
+    }
+}
+
+impl Mul for Jet {
+    type Output = Jet;
+    fn mul(self, other: Jet) -> Jet {
+        let n = self.coeffs.len().max(other.coeffs.len());
+        Jet {
+            coeffs: (0..n).map(|k| (0..=k).map(|i| self.get(i) * other.get(k - i)).sum()).collect()
+        }
+    }
+}
+
+impl Mul<f64> for Jet {
+    type Output = Jet;
+    fn mul(self, other: f64) -> Jet {
+        Jet {coeffs: self.coeffs.iter().map(|c| c * other).collect()}
+    }
+}
+
+impl Div for Jet {
+    type Output = Jet;
+    fn div(self, other: Jet) -> Jet {
+        let n = self.coeffs.len().max(other.coeffs.len());
+        let b0 = other.get(0);
+        let mut coeffs: Vec<f64> = Vec::with_capacity(n);
+        for k in 0..n {
+            let s: f64 = (1..=k).map(|i| other.get(i) * coeffs[k - i]).sum();
+            coeffs.push((self.get(k) - s) / b0);
+        }
+        Jet {coeffs}
+    }
+}
+
+impl Div<f64> for Jet {
+    type Output = Jet;
+    fn div(self, other: f64) -> Jet {
+        Jet {coeffs: self.coeffs.iter().map(|c| c / other).collect()}
+    }
+}
+
+impl Neg for Jet {
+    type Output = Jet;
+    fn neg(self) -> Jet {
+        Jet {coeffs: self.coeffs.iter().map(|c| -c).collect()}
+    }
+}
+
+/// A curve with coordinates computed over jets.
+///
+/// Since the coordinate functions propagate derivatives of any order,
+/// derivatives of the curve are exact instead of finite differences.
+/// The curve evaluates to its derivative of order `order`,
+/// such that differentiating only increments the order and can be repeated.
+#[derive(Clone)]
+pub struct DualCurve {
+    /// Function for x-coordinates.
+    pub x: Func<Jet, Jet>,
+    /// Function for y-coordinates.
+    pub y: Func<Jet, Jet>,
+    /// Function for z-coordinates.
+    pub z: Func<Jet, Jet>,
+    /// The order of the derivative the curve evaluates to.
+    pub order: usize,
+}
+
+impl DualCurve {
+    /// Creates a new curve from coordinate functions over jets.
+    ///
+    /// The functions must be built from jet arithmetic on their argument,
+    /// for example `|t: Jet| (t * TAU).cos()`, such that derivatives propagate.
+    pub fn new<X, Y, Z>(x: X, y: Y, z: Z) -> DualCurve
+        where X: 'static + Fn(Jet) -> Jet + Send + Sync,
+              Y: 'static + Fn(Jet) -> Jet + Send + Sync,
+              Z: 'static + Fn(Jet) -> Jet + Send + Sync
+    {
+        DualCurve {
+            x: Arc::new(x),
+            y: Arc::new(y),
+            z: Arc::new(z),
+            order: 0,
+        }
+    }
+
+    /// Creates a new circle in the xy-plane, like `PointFunc::circle`.
+    pub fn circle() -> DualCurve {
+        DualCurve::new(
+            |t: Jet| (t * TAU).cos(),
+            |t: Jet| (t * TAU).sin(),
+            |_| Jet::constant(0.0),
+        )
+    }
+
+    /// Returns the derivatives of the coordinate functions of order `0` to `k` at `t`.
+    fn derivatives(&self, t: f64, k: usize) -> Vec<Point> {
+        let x = (self.x)(Jet::var(t, k));
+        let y = (self.y)(Jet::var(t, k));
+        let z = (self.z)(Jet::var(t, k));
+        (0..=k).map(|i| Point::new(x.derivative(i), y.derivative(i), z.derivative(i))).collect()
+    }
+
+    /// Evaluates the derivative of order `order` at `t`.
+    pub fn call(&self, t: f64) -> Point {
+        self.derivatives(t, self.order)[self.order]
+    }
+
+    /// Returns the curve as a point function.
+    pub fn curve(&self) -> PointFunc<f64> {
+        let c = self.clone();
+        PointFunc::from_fn(move |t| c.call(t))
+    }
+
+    /// Returns the exact derivative of the curve.
+    pub fn diff_exact(self) -> Self {
+        DualCurve {order: self.order + 1, ..self}
+    }
+
+    /// Returns the exact curvature `|d × dd| / |d|³` at `t`.
+    pub fn curvature(&self, t: f64) -> f64 {
+        let ds = self.derivatives(t, self.order + 2);
+        let (d1, d2) = (ds[self.order + 1], ds[self.order + 2]);
+        d1.cross(d2).norm() / d1.norm().powi(3)
+    }
+
+    /// Returns the exact Frenet frame `(tangent, normal, binormal)` at `t`.
+    ///
+    /// Where the curvature is zero, the normal is picked like `PointFunc::frenet_frame`.
+    pub fn frenet_frame(&self, t: f64) -> (Point, Point, Point) {
+        let ds = self.derivatives(t, self.order + 2);
+        let (d1, d2) = (ds[self.order + 1], ds[self.order + 2]);
+        let tangent = d1.normalize();
+        let b = d1.cross(d2);
+        let normal = if b.norm() > 0.0 {
+            b.cross(d1).normalize()
+        } else {
+            fallback_normal(tangent)
+        };
+        (tangent, normal, tangent.cross(normal))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dual_ops() {
+        let x = Dual::var(2.0);
+        assert_eq!(x * x, Dual {re: 4.0, du: 4.0});
+        assert_eq!(x.powi(3), Dual {re: 8.0, du: 12.0});
+        assert_eq!(Dual::constant(1.0) / x, Dual {re: 0.5, du: -0.25});
+        assert_eq!(x.sqrt().du, 0.5 / 2.0f64.sqrt());
+        assert_eq!((x * 3.0 + 1.0 - x).du, 2.0);
+        assert_eq!((-x).du, -1.0);
+        assert_eq!(x.exp().du, 2.0f64.exp());
+        assert_eq!(x.ln().du, 0.5);
+    }
+
+    #[test]
+    fn jet_ops() {
+        let x = Jet::var(2.0, 3);
+        let y = x.clone() * x.clone() * x.clone();
+        assert_eq!(y, x.powi(3));
+        assert_eq!((0..4).map(|k| y.derivative(k)).collect::<Vec<_>>(), vec![8.0, 12.0, 12.0, 6.0]);
+        let r = Jet::constant(1.0) / x.clone();
+        assert_eq!(r.derivative(2), 2.0 / 8.0);
+        assert_eq!(x.powi(-1), r);
+        let e = x.exp();
+        for k in 0..4 {
+            assert!((e.derivative(k) - 2.0f64.exp()).abs() < 1e-12);
+        }
+        let l = x.ln();
+        assert!((l.derivative(3) - 2.0 / 8.0).abs() < 1e-12);
+        let s = x.sqrt();
+        assert!((s.derivative(2) + 0.25 * 2.0f64.powf(-1.5)).abs() < 1e-12);
+        assert_eq!((x.clone() * 3.0 + 1.0 - x.clone()).derivative(1), 2.0);
+        assert_eq!((-x.clone() / 2.0).derivative(1), -0.5);
+        let (sin, cos) = x.sin_cos();
+        assert!((sin.derivative(3) + 2.0f64.cos()).abs() < 1e-12);
+        assert!((cos.derivative(2) + 2.0f64.cos()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn circle_exact() {
+        let a = DualCurve::circle();
+        let b = a.curve();
+        let c = Point::circle();
+        for &t in &[0.0, 0.3, 0.7] {
+            assert_eq!(b.call(t), c.call(t));
+        }
+
+        let da = a.clone().diff_exact();
+        assert_eq!(da.call(0.0), Point::new(0.0, TAU, 0.0));
+        assert!(da.call(0.25).approx_eq(&Point::new(-TAU, 0.0, 0.0), 1e-12));
+
+        let dda = da.clone().diff_exact();
+        assert_eq!(dda.order, 2);
+        assert_eq!(dda.call(0.0), Point::new(-TAU * TAU, 0.0, 0.0));
+        assert_eq!(dda.curve().call(0.0), dda.call(0.0));
+
+        for &t in &[0.0, 0.1, 0.6] {
+            assert!((a.curvature(t) - 1.0).abs() < 1e-12);
+        }
+        // The derivative of a circle is a circle of radius `TAU`.
+        assert!((da.curvature(0.3) - 1.0 / TAU).abs() < 1e-12);
+
+        let (t, n, b) = a.frenet_frame(0.0);
+        assert_eq!(t, Point::new(0.0, 1.0, 0.0));
+        assert_eq!(n, Point::new(-1.0, 0.0, 0.0));
+        assert_eq!(b, Point::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn line_exact() {
+        let a = DualCurve::new(
+            |t: Jet| t.clone() * 3.3 + 1234.5,
+            |t: Jet| t * 7.7 + 500.1,
+            |t: Jet| t * 2.9,
+        );
+        assert_eq!(a.curvature(0.5), 0.0);
+        let (t0, n0, _) = a.frenet_frame(0.0);
+        let (t1, n1, _) = a.frenet_frame(1.0);
+        assert_eq!((t0, n0), (t1, n1));
+        assert_eq!(a.diff_exact().diff_exact().call(0.3), Point::origin());
+    }
+}
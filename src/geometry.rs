@@ -0,0 +1,338 @@
+//! Geometric queries.
+
+use super::*;
+
+/// Returns the nearest non-negative ray parameter where a ray hits a sphere.
+///
+/// The ray is `origin + dir * t`, where `dir` does not need to be normalized.
+/// Returns `None` if the ray misses the sphere.
+pub fn ray_sphere(origin: Point, dir: Point, center: Point, radius: f64) -> Option<f64> {
+    let oc = origin - center;
+    let a = dir.dot(dir);
+    let b = 2.0 * oc.dot(dir);
+    let c = oc.dot(oc) - radius * radius;
+    let disc = b * b - 4.0 * a * c;
+    if a == 0.0 || disc < 0.0 {return None};
+    let sq = disc.sqrt();
+    let t0 = (-b - sq) / (2.0 * a);
+    let t1 = (-b + sq) / (2.0 * a);
+    if t0 >= 0.0 {Some(t0)}
+    else if t1 >= 0.0 {Some(t1)}
+    else {None}
+}
+
+/// Returns the ray parameter where a ray intersects a plane.
+///
+/// The ray is `origin + dir * t` and the parameter is negative
+/// when the plane is behind the origin.
+/// Returns `None` if the ray is parallel to the plane.
+pub fn ray_plane(origin: Point, dir: Point, plane_point: Point, normal: Point) -> Option<f64> {
+    let d = dir.dot(normal);
+    if d == 0.0 {return None};
+    Some((plane_point - origin).dot(normal) / d)
+}
+
+/// Creates a point from spherical coordinates.
+///
+/// `theta` is the angle from the z-axis and `phi` the angle around it from the x-axis.
+pub fn from_spherical(r: f64, theta: f64, phi: f64) -> Point {
+    Point {
+        x: r * theta.sin() * phi.cos(),
+        y: r * theta.sin() * phi.sin(),
+        z: r * theta.cos(),
+    }
+}
+
+/// Creates a point from cylindrical coordinates.
+///
+/// `phi` is the angle around the z-axis from the x-axis.
+pub fn from_cylindrical(rho: f64, phi: f64, z: f64) -> Point {
+    Point {
+        x: rho * phi.cos(),
+        y: rho * phi.sin(),
+        z,
+    }
+}
+
+/// Returns an orthonormal `(forward, right, up)` basis looking from `eye` towards `target`.
+///
+/// When the forward direction is parallel to `up`,
+/// another axis is picked to keep the basis well defined.
+///
+/// # Panics
+///
+/// Panics if `eye` equals `target`, since there is no forward direction.
+pub fn look_at(eye: Point, target: Point, up: Point) -> (Point, Point, Point) {
+    assert!(eye != target, "look_at requires `eye` to differ from `target`");
+    let forward = (target - eye).normalize();
+    let mut right = forward.cross(up);
+    if right.norm() <= 0.000001 * up.norm() {
+        let axis = if forward.x.abs() < 0.9 {
+            Point::new(1.0, 0.0, 0.0)
+        } else {
+            Point::new(0.0, 1.0, 0.0)
+        };
+        right = forward.cross(axis);
+    }
+    let right = right.normalize();
+    (forward, right, right.cross(forward))
+}
+
+/// Returns the point on triangle `a`, `b`, `c` that is closest to `p`.
+///
+/// Handles the face, edge and corner regions of the triangle.
+pub fn closest_point_on_triangle(p: Point, a: Point, b: Point, c: Point) -> Point {
+    let ab = b - a;
+    let ac = c - a;
+    let ap = p - a;
+    let d1 = ab.dot(ap);
+    let d2 = ac.dot(ap);
+    if d1 <= 0.0 && d2 <= 0.0 {return a};
+
+    let bp = p - b;
+    let d3 = ab.dot(bp);
+    let d4 = ac.dot(bp);
+    if d3 >= 0.0 && d4 <= d3 {return b};
+
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        return a + ab * (d1 / (d1 - d3));
+    }
+
+    let cp = p - c;
+    let d5 = ab.dot(cp);
+    let d6 = ac.dot(cp);
+    if d6 >= 0.0 && d5 <= d6 {return c};
+
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        return a + ac * (d2 / (d2 - d6));
+    }
+
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && d4 - d3 >= 0.0 && d5 - d6 >= 0.0 {
+        return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+    }
+
+    let denom = 1.0 / (va + vb + vc);
+    a + ab * (vb * denom) + ac * (vc * denom)
+}
+
+/// Returns the barycentric coordinates of `p` relative to triangle `a`, `b`, `c`.
+///
+/// Points outside the plane of the triangle are projected onto it.
+/// Returns NaN for every weight if the triangle is degenerate,
+/// which includes slivers where the sine of the angle at `a` is below `1e-6`.
+pub fn barycentric_coords(p: Point, a: Point, b: Point, c: Point) -> [f64; 3] {
+    let v0 = b - a;
+    let v1 = c - a;
+    let v2 = p - a;
+    let d00 = v0.dot(v0);
+    let d01 = v0.dot(v1);
+    let d11 = v1.dot(v1);
+    let d20 = v2.dot(v0);
+    let d21 = v2.dot(v1);
+    let denom = d00 * d11 - d01 * d01;
+    // `denom` is `d00 * d11 * sin²` of the angle at `a`, so compare it relative to the edges.
+    if denom.abs() <= 1e-12 * d00 * d11 {return [f64::NAN; 3]};
+    let v = (d11 * d20 - d01 * d21) / denom;
+    let w = (d00 * d21 - d01 * d20) / denom;
+    [1.0 - v - w, v, w]
+}
+
+/// Returns `true` if `p` projects into triangle `a`, `b`, `c`.
+///
+/// Barycentric weights are allowed to be down to `-eps`, to include points on the edges.
+/// Always returns `false` for degenerate triangles.
+pub fn point_in_triangle(p: Point, a: Point, b: Point, c: Point, eps: f64) -> bool {
+    barycentric_coords(p, a, b, c).iter().all(|&w| w >= -eps)
+}
+
+/// Returns the angles between the tangents at the joins of consecutive curve segments.
+///
+/// Compares the end tangent of each segment at `1` with the start tangent of the next at `0`,
+/// using one-sided differences with step `eps`.
+/// An angle of zero means the join is tangent continuous (G1).
+pub fn tangent_discontinuities(segments: &[PointFunc<f64>], eps: f64) -> Vec<f64> {
+    segments.windows(2).map(|w| {
+        let end = w[0].call(1.0) - w[0].call(1.0 - eps);
+        let start = w[1].call(eps) - w[1].call(0.0);
+        end.angle_between(start)
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tangent_discontinuities() {
+        let a = bezier(&[Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0)]);
+        let b = bezier(&[Point::new(1.0, 0.0, 0.0), Point::new(2.0, 0.0, 0.0)]);
+        let c = bezier(&[Point::new(2.0, 0.0, 0.0), Point::new(2.0, 1.0, 0.0)]);
+        let gaps = tangent_discontinuities(&[a, b, c], 1e-6);
+        assert_eq!(gaps.len(), 2);
+        assert!(gaps[0].abs() < 1e-6);
+        assert!((gaps[1] - 0.25 * TAU).abs() < 1e-6);
+        assert!(tangent_discontinuities(&[Point::circle()], 1e-6).is_empty());
+    }
+
+    #[test]
+    fn test_ray_sphere() {
+        let center: Point = [0.0, 0.0, 0.0].into();
+        // Hit.
+        let t = ray_sphere([0.0, 0.0, -5.0].into(), [0.0, 0.0, 2.0].into(), center, 1.0);
+        assert_eq!(t, Some(2.0));
+        // Miss.
+        let t = ray_sphere([0.0, 2.0, -5.0].into(), [0.0, 0.0, 1.0].into(), center, 1.0);
+        assert_eq!(t, None);
+        // Tangent.
+        let t = ray_sphere([0.0, 1.0, -5.0].into(), [0.0, 0.0, 1.0].into(), center, 1.0);
+        assert_eq!(t, Some(5.0));
+        // Inside.
+        let t = ray_sphere(center, [1.0, 0.0, 0.0].into(), center, 1.0);
+        assert_eq!(t, Some(1.0));
+        // Behind.
+        let t = ray_sphere([0.0, 0.0, 5.0].into(), [0.0, 0.0, 1.0].into(), center, 1.0);
+        assert_eq!(t, None);
+    }
+
+    #[test]
+    fn test_ray_plane() {
+        let ground: Point = [0.0, 0.0, 0.0].into();
+        let up: Point = [0.0, 0.0, 1.0].into();
+        let t = ray_plane([1.0, 2.0, 4.0].into(), [0.0, 1.0, -2.0].into(), ground, up);
+        assert_eq!(t, Some(2.0));
+        let t = ray_plane([1.0, 2.0, 4.0].into(), [0.0, 1.0, 2.0].into(), ground, up);
+        assert_eq!(t, Some(-2.0));
+        // Parallel.
+        let t = ray_plane([1.0, 2.0, 4.0].into(), [1.0, 1.0, 0.0].into(), ground, up);
+        assert_eq!(t, None);
+    }
+
+    #[test]
+    fn spherical() {
+        let points = [
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(0.0, 2.0, 0.0),
+            Point::new(0.0, 0.0, -3.0),
+            Point::new(1.0, -2.0, 3.0),
+            Point::new(-0.5, -0.5, 0.25),
+        ];
+        for &p in &points {
+            let (r, theta, phi) = p.to_spherical();
+            assert!(from_spherical(r, theta, phi).approx_eq(&p, 1e-12));
+        }
+        assert_eq!(Point::new(0.0, 0.0, 2.0).to_spherical(), (2.0, 0.0, 0.0));
+        assert_eq!(Point::origin().to_spherical(), (0.0, 0.0, 0.0));
+        assert_eq!(from_spherical(0.0, 1.0, 2.0), Point::origin());
+    }
+
+    #[test]
+    fn cylindrical() {
+        let points = [
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(0.0, 2.0, 1.0),
+            Point::new(1.0, -2.0, 3.0),
+            Point::new(-0.5, -0.5, -0.25),
+        ];
+        for &p in &points {
+            let (rho, phi, z) = p.to_cylindrical();
+            assert!(from_cylindrical(rho, phi, z).approx_eq(&p, 1e-12));
+        }
+        assert_eq!(Point::new(0.0, 0.0, 2.0).to_cylindrical(), (0.0, 0.0, 2.0));
+        assert_eq!(from_cylindrical(0.0, 1.0, 2.0), Point::new(0.0, 0.0, 2.0));
+    }
+
+    #[test]
+    fn test_look_at() {
+        let (f, r, u) = look_at(
+            Point::new(0.0, 0.0, 5.0),
+            Point::origin(),
+            Point::new(0.0, 2.0, 0.0)
+        );
+        assert_eq!(f, Point::new(0.0, 0.0, -1.0));
+        assert_eq!(r, Point::new(1.0, 0.0, 0.0));
+        assert_eq!(u, Point::new(0.0, 1.0, 0.0));
+
+        // Degenerate case where forward is parallel to up.
+        let (f, r, u) = look_at(
+            Point::origin(),
+            Point::new(0.0, 3.0, 0.0),
+            Point::new(0.0, 1.0, 0.0)
+        );
+        assert_eq!(f, Point::new(0.0, 1.0, 0.0));
+        assert!((r.norm() - 1.0).abs() < 1e-12);
+        assert!((u.norm() - 1.0).abs() < 1e-12);
+        assert!(f.dot(r).abs() < 1e-12);
+        assert!(f.dot(u).abs() < 1e-12);
+        assert!(r.dot(u).abs() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_look_at_eye_at_target() {
+        let p = Point::new(1.0, 2.0, 3.0);
+        let _ = look_at(p, p, Point::new(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn distance_to_triangle() {
+        let a = Point::new(0.0, 0.0, 0.0);
+        let b = Point::new(2.0, 0.0, 0.0);
+        let c = Point::new(0.0, 2.0, 0.0);
+        // Face.
+        assert_eq!(Point::new(0.5, 0.5, 3.0).distance_to_triangle(a, b, c), 3.0);
+        assert_eq!(Point::new(0.5, 0.5, 0.0).distance_to_triangle(a, b, c), 0.0);
+        // Edges.
+        assert_eq!(Point::new(1.0, -2.0, 0.0).distance_to_triangle(a, b, c), 2.0);
+        assert_eq!(Point::new(-1.0, 1.0, 0.0).distance_to_triangle(a, b, c), 1.0);
+        let d = Point::new(2.0, 2.0, 0.0).distance_to_triangle(a, b, c);
+        assert!((d - 2.0f64.sqrt()).abs() < 1e-12);
+        // Corners.
+        assert_eq!(Point::new(-3.0, -4.0, 0.0).distance_to_triangle(a, b, c), 5.0);
+        assert_eq!(Point::new(5.0, -4.0, 0.0).distance_to_triangle(a, b, c), 5.0);
+        assert_eq!(Point::new(0.0, 3.0, 0.0).distance_to_triangle(a, b, c), 1.0);
+    }
+
+    #[test]
+    fn test_barycentric_coords() {
+        let a = Point::new(0.0, 0.0, 1.0);
+        let b = Point::new(3.0, 0.0, 1.0);
+        let c = Point::new(0.0, 3.0, 1.0);
+        let centroid = (a + b + c) * (1.0 / 3.0);
+        let w = barycentric_coords(centroid, a, b, c);
+        for &wi in &w {
+            assert!((wi - 1.0 / 3.0).abs() < 1e-12);
+        }
+        assert_eq!(barycentric_coords(a, a, b, c), [1.0, 0.0, 0.0]);
+        assert_eq!(barycentric_coords(b, a, b, c), [0.0, 1.0, 0.0]);
+        assert_eq!(barycentric_coords(c, a, b, c), [0.0, 0.0, 1.0]);
+        let w = barycentric_coords(a, a, b, b * 2.0 - a);
+        assert!(w.iter().all(|wi| wi.is_nan()));
+        // Nearly collinear after rounding.
+        let sliver = b * 2.0 - a + Point::new(0.0, 1e-9, 0.0);
+        let w = barycentric_coords(centroid, a, b, sliver);
+        assert!(w.iter().all(|wi| wi.is_nan()));
+        let w = barycentric_coords(a, a, a, c);
+        assert!(w.iter().all(|wi| wi.is_nan()));
+    }
+
+    #[test]
+    fn test_point_in_triangle() {
+        let a = Point::new(0.0, 0.0, 0.0);
+        let b = Point::new(1.0, 0.0, 0.0);
+        let c = Point::new(0.0, 1.0, 0.0);
+        let eps = 1e-9;
+        // Interior.
+        assert!(point_in_triangle(Point::new(0.25, 0.25, 0.0), a, b, c, eps));
+        // Edge.
+        assert!(point_in_triangle(Point::new(0.5, 0.0, 0.0), a, b, c, eps));
+        assert!(point_in_triangle(Point::new(0.5, 0.5, 0.0), a, b, c, eps));
+        // Exterior.
+        assert!(!point_in_triangle(Point::new(0.6, 0.6, 0.0), a, b, c, eps));
+        assert!(!point_in_triangle(Point::new(-0.1, 0.5, 0.0), a, b, c, eps));
+        // Degenerate.
+        assert!(!point_in_triangle(a, a, b, b, eps));
+    }
+}
@@ -7,16 +7,36 @@ extern crate higher_order_core;
 use higher_order_core::*;
 
 use std::sync::Arc;
-use std::ops::{Add, Div, Mul, Sub};
+use std::ops::{Add, AddAssign, Div, Index, IndexMut, Mul, MulAssign, Sub, SubAssign};
 
 /// A point function.
+///
+/// Any `Copy` argument type can be called, including arrays of any length, e.g. `[f64; 4]`.
 pub type PointFunc<T> = Point<Arg<T>>;
 
+pub use bezier::*;
+pub use dual::*;
+pub use geometry::*;
 pub use math::*;
+pub use mesh::*;
 pub use ops::*;
-
+pub use points::*;
+#[cfg(feature = "renderer")]
+pub use renderer::*;
+pub use rotation::*;
+pub use spline::*;
+
+pub mod bezier;
+pub mod dual;
+pub mod geometry;
 pub mod math;
+pub mod mesh;
 pub mod ops;
+pub mod points;
+#[cfg(feature = "renderer")]
+pub mod renderer;
+pub mod rotation;
+pub mod spline;
 
 /// 3D point.
 #[derive(Clone)]
@@ -31,6 +51,119 @@ pub struct Point<T = ()> where f64: Ho<T> {
 
 impl Copy for Point {}
 
+impl Point {
+    /// Creates a new point.
+    pub fn new(x: f64, y: f64, z: f64) -> Point {
+        Point {x, y, z}
+    }
+
+    /// Returns the origin.
+    pub fn origin() -> Point {
+        Point {x: 0.0, y: 0.0, z: 0.0}
+    }
+
+    /// Returns the Euclidean distance to another point.
+    pub fn distance(self, other: Point) -> f64 {
+        (self - other).norm()
+    }
+
+    /// Returns the point scaled to unit length.
+    pub fn normalize(self) -> Point {
+        self * (1.0 / self.norm())
+    }
+
+    /// Returns the angle in radians between two directions.
+    pub fn angle_between(self, other: Point) -> f64 {
+        (self.dot(other) / (self.norm() * other.norm())).clamp(-1.0, 1.0).acos()
+    }
+
+    /// Returns the componentwise minimum of two points.
+    pub fn min(self, other: Point) -> Point {
+        Point {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+            z: self.z.min(other.z),
+        }
+    }
+
+    /// Returns the componentwise maximum of two points.
+    pub fn max(self, other: Point) -> Point {
+        Point {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+            z: self.z.max(other.z),
+        }
+    }
+
+    /// Clamps each coordinate into the box between `min` and `max`.
+    pub fn clamp_box(self, min: Point, max: Point) -> Point {
+        self.max(min).min(max)
+    }
+
+    /// Returns spherical coordinates `(r, theta, phi)`.
+    ///
+    /// `theta` is the angle from the z-axis and `phi` the angle around it from the x-axis.
+    /// Both angles are zero at the origin.
+    pub fn to_spherical(self) -> (f64, f64, f64) {
+        let r = self.norm();
+        if r == 0.0 {return (0.0, 0.0, 0.0)};
+        (r, (self.z / r).clamp(-1.0, 1.0).acos(), self.y.atan2(self.x))
+    }
+
+    /// Returns cylindrical coordinates `(rho, phi, z)`.
+    ///
+    /// `phi` is the angle around the z-axis from the x-axis, which is zero on the axis.
+    pub fn to_cylindrical(self) -> (f64, f64, f64) {
+        let rho = self.x.hypot(self.y);
+        let phi = if rho == 0.0 {0.0} else {self.y.atan2(self.x)};
+        (rho, phi, self.z)
+    }
+
+    /// Returns `true` if no coordinate is infinite or NaN.
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
+    /// Replaces each coordinate that is infinite or NaN with the fallback's.
+    pub fn sanitize(self, fallback: Point) -> Point {
+        let f = |a: f64, b: f64| if a.is_finite() {a} else {b};
+        Point {
+            x: f(self.x, fallback.x),
+            y: f(self.y, fallback.y),
+            z: f(self.z, fallback.z),
+        }
+    }
+
+    /// Returns the perpendicular distance to the infinite line through `a` and `b`.
+    ///
+    /// Returns the distance to `a` if `a` and `b` are equal.
+    pub fn distance_to_line(self, a: Point, b: Point) -> f64 {
+        let ab = b - a;
+        let len = ab.norm();
+        if len == 0.0 {return self.distance(a)};
+        (self - a).cross(ab).norm() / len
+    }
+
+    /// Returns the distance to the nearest point on triangle `a`, `b`, `c`.
+    pub fn distance_to_triangle(self, a: Point, b: Point, c: Point) -> f64 {
+        self.distance(closest_point_on_triangle(self, a, b, c))
+    }
+
+    /// Returns `true` if each coordinate is within `eps` of the other point.
+    pub fn approx_eq(&self, other: &Point, eps: f64) -> bool {
+        (self.x - other.x).abs() <= eps &&
+        (self.y - other.y).abs() <= eps &&
+        (self.z - other.z).abs() <= eps
+    }
+
+    /// Applies a 4x4 column matrix transform, dividing by `w`.
+    #[cfg(feature = "vecmath")]
+    pub fn transform(self, m: vecmath::Matrix4<f64>) -> Point {
+        let p = vecmath::col_mat4_transform(m, [self.x, self.y, self.z, 1.0]);
+        Point {x: p[0] / p[3], y: p[1] / p[3], z: p[2] / p[3]}
+    }
+}
+
 impl PointFunc<[f64; 2]> {
     /// Returns ground plane with zero z-values.
     pub fn ground_plane() -> Self {
@@ -40,6 +173,123 @@ impl PointFunc<[f64; 2]> {
             z: zero(),
         }
     }
+
+    /// Returns the product surface of two curves.
+    ///
+    /// The point at `[u, v]` is the sum `a(u) + b(v)`.
+    pub fn product(a: PointFunc<f64>, b: PointFunc<f64>) -> Self {
+        let (ax, ay, az) = (a.x, a.y, a.z);
+        let (bx, by, bz) = (b.x, b.y, b.z);
+        Point {
+            x: Arc::new(move |p: [f64; 2]| ax(p[0]) + bx(p[1])),
+            y: Arc::new(move |p: [f64; 2]| ay(p[0]) + by(p[1])),
+            z: Arc::new(move |p: [f64; 2]| az(p[0]) + bz(p[1])),
+        }
+    }
+
+    /// Splits the surface at `at` in the first parameter.
+    ///
+    /// Returns the parts over `[0, at]` and `[at, 1]`, each reparameterized to `[0, 1]`.
+    pub fn split_u(self, at: f64) -> (Self, Self) {
+        (
+            self.clone().map(move |p: [f64; 2]| [p[0] * at, p[1]]),
+            self.map(move |p: [f64; 2]| [at + p[0] * (1.0 - at), p[1]]),
+        )
+    }
+
+    /// Splits the surface at `at` in the second parameter.
+    ///
+    /// Returns the parts over `[0, at]` and `[at, 1]`, each reparameterized to `[0, 1]`.
+    pub fn split_v(self, at: f64) -> (Self, Self) {
+        (
+            self.clone().map(move |p: [f64; 2]| [p[0], p[1] * at]),
+            self.map(move |p: [f64; 2]| [p[0], at + p[1] * (1.0 - at)]),
+        )
+    }
+
+    /// Samples a grid of `n[0]` rows and `n[1]` columns over `[0, 1]²`.
+    ///
+    /// The end points are included, such that the corners of the grid
+    /// are the corners of the surface.
+    pub fn sample_grid(&self, n: [usize; 2]) -> Vec<Vec<Point>> {
+        (0..n[0]).map(|i| {
+            let u = grid_param(i, n[0]);
+            (0..n[1]).map(|j| self.call([u, grid_param(j, n[1])])).collect()
+        }).collect()
+    }
+
+    /// Samples a grid like `sample_grid` into a flat row-major buffer, replacing its contents.
+    pub fn sample_grid_into(&self, out: &mut Vec<Point>, n: [usize; 2]) {
+        out.clear();
+        out.reserve(n[0] * n[1]);
+        for i in 0..n[0] {
+            let u = grid_param(i, n[0]);
+            out.extend((0..n[1]).map(|j| self.call([u, grid_param(j, n[1])])));
+        }
+    }
+
+    /// Returns the unit tangents of the surface in the first and second parameter.
+    ///
+    /// Uses forward differences with step `eps`, like `surface_normal`.
+    pub fn tangent_basis(&self, eps: f64) -> (Self, Self) {
+        fn partial(s: PointFunc<[f64; 2]>, eps: f64, dir: [f64; 2]) -> PointFunc<[f64; 2]> {
+            let f = Arc::new(move |p: [f64; 2]| {
+                let q = [p[0] + dir[0] * eps, p[1] + dir[1] * eps];
+                (s.call(q) - s.call(p)).normalize()
+            });
+            let fx = f.clone();
+            let fy = f.clone();
+            let fz = f;
+            Point {
+                x: Arc::new(move |p| fx(p).x),
+                y: Arc::new(move |p| fy(p).y),
+                z: Arc::new(move |p| fz(p).z),
+            }
+        }
+        (partial(self.clone(), eps, [1.0, 0.0]), partial(self.clone(), eps, [0.0, 1.0]))
+    }
+
+    /// Returns the unit normals of the surface.
+    ///
+    /// The normal is the cross product of the partial derivatives
+    /// in the first and second parameter, using forward differences with step `eps`.
+    pub fn surface_normal(&self, eps: f64) -> Self {
+        let s = self.clone();
+        let f = Arc::new(move |p: [f64; 2]| {
+            let a = s.call(p);
+            let du = s.call([p[0] + eps, p[1]]) - a;
+            let dv = s.call([p[0], p[1] + eps]) - a;
+            du.cross(dv).normalize()
+        });
+        let fx = f.clone();
+        let fy = f.clone();
+        let fz = f;
+        Point {
+            x: Arc::new(move |p| fx(p).x),
+            y: Arc::new(move |p| fy(p).y),
+            z: Arc::new(move |p| fz(p).z),
+        }
+    }
+}
+
+/// Returns the parameter of grid index `i` out of `n` over `[0, 1]`.
+fn grid_param(i: usize, n: usize) -> f64 {
+    if n > 1 {i as f64 / (n - 1) as f64} else {0.0}
+}
+
+/// Returns a normal for a straight segment with the given unit tangent.
+///
+/// Crosses the tangent with the coordinate axis least aligned with it,
+/// such that the normal is fixed along the segment.
+fn fallback_normal(tangent: Point) -> Point {
+    let axis = if tangent.x.abs() <= tangent.y.abs() && tangent.x.abs() <= tangent.z.abs() {
+        Point::new(1.0, 0.0, 0.0)
+    } else if tangent.y.abs() <= tangent.z.abs() {
+        Point::new(0.0, 1.0, 0.0)
+    } else {
+        Point::new(0.0, 0.0, 1.0)
+    };
+    tangent.cross(axis).normalize()
 }
 
 impl PointFunc<[f64; 3]> {
@@ -69,6 +319,37 @@ impl PartialEq for Point {
     }
 }
 
+impl Index<usize> for Point {
+    type Output = f64;
+    fn index(&self, axis: usize) -> &f64 {
+        match axis {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Point axis out of bounds: {}", axis),
+        }
+    }
+}
+
+impl IndexMut<usize> for Point {
+    fn index_mut(&mut self, axis: usize) -> &mut f64 {
+        match axis {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("Point axis out of bounds: {}", axis),
+        }
+    }
+}
+
+impl IntoIterator for Point {
+    type Item = f64;
+    type IntoIter = std::array::IntoIter<f64, 3>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIterator::into_iter([self.x, self.y, self.z])
+    }
+}
+
 impl Dot for Point {
     type Output = f64;
     fn dot(self, other: Self) -> f64 {
@@ -91,6 +372,20 @@ impl<T: 'static + Copy> Dot for PointFunc<T> {
     }
 }
 
+impl<T: 'static + Copy> Dot<PointFunc<T>> for Point {
+    type Output = Func<T, f64>;
+    fn dot(self, other: PointFunc<T>) -> Func<T, f64> {
+        PointFunc::constant(self).dot(other)
+    }
+}
+
+impl<T: 'static + Copy> Dot<Point> for PointFunc<T> {
+    type Output = Func<T, f64>;
+    fn dot(self, other: Point) -> Func<T, f64> {
+        self.dot(PointFunc::constant(other))
+    }
+}
+
 impl Cross for Point {
     type Output = Point;
     fn cross(self, other: Self) -> Self {
@@ -107,26 +402,34 @@ impl Cross for Point {
 impl<T: 'static + Copy> Cross for PointFunc<T> {
     type Output = Self;
     fn cross(self, other: Self) -> Self {
-        let ax1 = self.x.clone();
-        let ax2 = self.x;
-        let ay1 = self.y.clone();
-        let ay2 = self.y;
-        let az1 = self.z.clone();
-        let az2 = self.z;
-        let bx1 = other.x.clone();
-        let bx2 = other.x;
-        let by1 = other.y.clone();
-        let by2 = other.y;
-        let bz1 = other.z.clone();
-        let bz2 = other.z;
+        let (ax, ay, az) = (self.x, self.y, self.z);
+        let (bx, by, bz) = (other.x, other.y, other.z);
+        // Each coordinate captures exactly the four functions it uses.
+        let (x_ay, x_az, x_by, x_bz) = (ay.clone(), az.clone(), by.clone(), bz.clone());
+        let (y_az, y_ax, y_bx, y_bz) = (az, ax.clone(), bx.clone(), bz);
+        let (z_ax, z_ay, z_bx, z_by) = (ax, ay, bx, by);
         Point {
-            x: Arc::new(move |v| ay1(v) * bz1(v) - az1(v) * by1(v)),
-            y: Arc::new(move |v| az2(v) * bx1(v) - ax1(v) * bz2(v)),
-            z: Arc::new(move |v| ax2(v) * by2(v) - ay2(v) * bx2(v)),
+            x: Arc::new(move |v| x_ay(v) * x_bz(v) - x_az(v) * x_by(v)),
+            y: Arc::new(move |v| y_az(v) * y_bx(v) - y_ax(v) * y_bz(v)),
+            z: Arc::new(move |v| z_ax(v) * z_by(v) - z_ay(v) * z_bx(v)),
         }
     }
 }
 
+impl<T: 'static + Copy> Cross<PointFunc<T>> for Point {
+    type Output = PointFunc<T>;
+    fn cross(self, other: PointFunc<T>) -> PointFunc<T> {
+        PointFunc::constant(self).cross(other)
+    }
+}
+
+impl<T: 'static + Copy> Cross<Point> for PointFunc<T> {
+    type Output = Self;
+    fn cross(self, other: Point) -> Self {
+        self.cross(PointFunc::constant(other))
+    }
+}
+
 impl Norm for Point {
     type Output = f64;
     fn norm(self) -> f64 {
@@ -145,6 +448,44 @@ impl<T: 'static + Copy> Norm for PointFunc<T> {
 }
 
 impl<T: 'static> PointFunc<T> {
+    /// Returns a function that ignores its argument and returns a constant point.
+    pub fn constant(p: Point) -> Self {
+        Point {
+            x: k(p.x),
+            y: k(p.y),
+            z: k(p.z),
+        }
+    }
+
+    /// Creates a point function from a single point-valued closure.
+    ///
+    /// The closure is called once per coordinate.
+    pub fn from_fn<F>(f: F) -> Self
+        where F: 'static + Fn(T) -> Point + Clone + Send + Sync
+    {
+        let f1 = f.clone();
+        let f2 = f.clone();
+        let f3 = f;
+        Point {
+            x: Arc::new(move |a| f1(a).x),
+            y: Arc::new(move |a| f2(a).y),
+            z: Arc::new(move |a| f3(a).z),
+        }
+    }
+
+    /// Creates a point function from three coordinate closures.
+    pub fn from_coords<X, Y, Z>(x: X, y: Y, z: Z) -> Self
+        where X: 'static + Fn(T) -> f64 + Send + Sync,
+              Y: 'static + Fn(T) -> f64 + Send + Sync,
+              Z: 'static + Fn(T) -> f64 + Send + Sync
+    {
+        Point {
+            x: Arc::new(x),
+            y: Arc::new(y),
+            z: Arc::new(z),
+        }
+    }
+
     /// Adds another parameter to the right.
     pub fn lift_right<U>(self) -> PointFunc<(T, U)> {
         let fx = self.x;
@@ -173,6 +514,151 @@ impl<T: 'static> PointFunc<T> {
     pub fn call(&self, val: T) -> Point where T: Copy {
         <Point as Call<T>>::call(self, val)
     }
+
+    /// Calls each value in a slice.
+    pub fn call_many(&self, vals: &[T]) -> Vec<Point> where T: Copy {
+        vals.iter().map(|&val| self.call(val)).collect()
+    }
+
+    /// Maps input into another, passing a sample index alongside the parameter.
+    ///
+    /// The index becomes part of the input instead of mutable state,
+    /// because the coordinate closures are shared and may be called in any order.
+    /// Per-sample state should therefore be computed from the index.
+    pub fn map_indexed<U, F>(self, f: F) -> PointFunc<(usize, U)>
+        where F: 'static + Fn(usize, U) -> T + Clone + Send + Sync
+    {
+        self.map(move |(i, u)| f(i, u))
+    }
+
+    /// Converts into a single function returning coordinates as an array.
+    pub fn into_array_fn(self) -> Func<T, [f64; 3]> where T: Copy {
+        let fx = self.x;
+        let fy = self.y;
+        let fz = self.z;
+        Arc::new(move |a| [fx(a), fy(a), fz(a)])
+    }
+
+    /// Applies a scalar function to each coordinate output.
+    ///
+    /// Unlike `map`, which transforms the input, this transforms the output.
+    pub fn map_coords<F>(self, f: F) -> Self
+        where F: 'static + Fn(f64) -> f64 + Clone + Send + Sync
+    {
+        let fx = self.x;
+        let fy = self.y;
+        let fz = self.z;
+        let f1 = f.clone();
+        let f2 = f.clone();
+        let f3 = f;
+        Point {
+            x: Arc::new(move |a| f1(fx(a))),
+            y: Arc::new(move |a| f2(fy(a))),
+            z: Arc::new(move |a| f3(fz(a))),
+        }
+    }
+
+    /// Applies a separate scalar function to each coordinate output.
+    pub fn map_xyz<FX, FY, FZ>(self, fx: FX, fy: FY, fz: FZ) -> Self
+        where FX: 'static + Fn(f64) -> f64 + Send + Sync,
+              FY: 'static + Fn(f64) -> f64 + Send + Sync,
+              FZ: 'static + Fn(f64) -> f64 + Send + Sync
+    {
+        let x = self.x;
+        let y = self.y;
+        let z = self.z;
+        Point {
+            x: Arc::new(move |a| fx(x(a))),
+            y: Arc::new(move |a| fy(y(a))),
+            z: Arc::new(move |a| fz(z(a))),
+        }
+    }
+
+    /// Reflects about the yz-plane by negating x-coordinates.
+    pub fn mirror_x(self) -> Self {
+        let fx = self.x;
+        Point {
+            x: Arc::new(move |a| -fx(a)),
+            y: self.y,
+            z: self.z,
+        }
+    }
+
+    /// Reflects about the xz-plane by negating y-coordinates.
+    pub fn mirror_y(self) -> Self {
+        let fy = self.y;
+        Point {
+            x: self.x,
+            y: Arc::new(move |a| -fy(a)),
+            z: self.z,
+        }
+    }
+
+    /// Reflects about the xy-plane by negating z-coordinates.
+    pub fn mirror_z(self) -> Self {
+        let fz = self.z;
+        Point {
+            x: self.x,
+            y: self.y,
+            z: Arc::new(move |a| -fz(a)),
+        }
+    }
+
+    /// Applies a constant 4x4 column matrix transform to each output, dividing by `w`.
+    ///
+    /// Since every row depends on the whole source point and the coordinates are
+    /// separate functions, evaluating all three coordinates of the result evaluates
+    /// the source point three times, besides the `w` row.
+    /// For expensive sources, such as `memoize` or `periodic_spline`,
+    /// prefer calling the source and then `Point::transform` on the evaluated point.
+    #[cfg(feature = "vecmath")]
+    pub fn transform(self, m: vecmath::Matrix4<f64>) -> Self where T: Copy {
+        fn row(m: &vecmath::Matrix4<f64>, p: Point, i: usize) -> f64 {
+            m[0][i] * p.x + m[1][i] * p.y + m[2][i] * p.z + m[3][i]
+        }
+        let px = self.clone();
+        let py = self.clone();
+        let pz = self;
+        Point {
+            x: Arc::new(move |a| {let p = px.call(a); row(&m, p, 0) / row(&m, p, 3)}),
+            y: Arc::new(move |a| {let p = py.call(a); row(&m, p, 1) / row(&m, p, 3)}),
+            z: Arc::new(move |a| {let p = pz.call(a); row(&m, p, 2) / row(&m, p, 3)}),
+        }
+    }
+}
+
+impl<A: 'static, B: 'static> PointFunc<(A, B)> {
+    /// Fixes the first argument, returning a function of the second.
+    ///
+    /// This is the inverse of `lift_left`.
+    pub fn fix_first(self, a: A) -> PointFunc<B>
+        where A: Copy + Send + Sync
+    {
+        let fx = self.x;
+        let fy = self.y;
+        let fz = self.z;
+        Point {
+            x: Arc::new(move |b| fx((a, b))),
+            y: Arc::new(move |b| fy((a, b))),
+            z: Arc::new(move |b| fz((a, b))),
+        }
+    }
+
+    /// Fixes the second argument, returning a function of the first.
+    ///
+    /// This is the inverse of `lift_right`.
+    pub fn fix_second(self, b: B) -> PointFunc<A>
+        where B: Copy + Send + Sync
+    {
+        let fx = self.x;
+        let fy = self.y;
+        let fz = self.z;
+        Point {
+            x: Arc::new(move |a| fx((a, b))),
+            y: Arc::new(move |a| fy((a, b))),
+            z: Arc::new(move |a| fz((a, b))),
+        }
+    }
 }
 
 impl<T: 'static, U> Map<T, U> for PointFunc<T> {
@@ -212,6 +698,59 @@ impl PointFunc<f64> {
         }
     }
 
+    /// Creates a new superellipse in the xy-plane with semi-axes `a` and `b`.
+    ///
+    /// When `n` is 2, this is an ellipse.
+    /// For large `n`, the shape approaches a rectangle.
+    pub fn superellipse(a: f64, b: f64, n: f64) -> Self {
+        let e = 2.0 / n;
+        Point {
+            x: Arc::new(move |ang: f64| {
+                let c = (ang * TAU).cos();
+                a * c.signum() * c.abs().powf(e)
+            }),
+            y: Arc::new(move |ang: f64| {
+                let s = (ang * TAU).sin();
+                b * s.signum() * s.abs().powf(e)
+            }),
+            z: zero(),
+        }
+    }
+
+    /// Creates a new rose curve in the xy-plane with radius `cos(petals * ang)`.
+    pub fn rose(petals: f64) -> Self {
+        Point {
+            x: Arc::new(move |ang: f64| (petals * ang * TAU).cos() * (ang * TAU).cos()),
+            y: Arc::new(move |ang: f64| (petals * ang * TAU).cos() * (ang * TAU).sin()),
+            z: zero(),
+        }
+    }
+
+    /// Creates a new epicycloid in the xy-plane.
+    ///
+    /// Traces a point on a circle of radius `r` rolling around a circle of radius `big_r`.
+    /// When `r == big_r`, this is a cardioid with a cusp at `(big_r, 0, 0)`.
+    pub fn epicycloid(r: f64, big_r: f64) -> Self {
+        let k = (big_r + r) / r;
+        Point {
+            x: Arc::new(move |ang: f64| (big_r + r) * (ang * TAU).cos() - r * (k * ang * TAU).cos()),
+            y: Arc::new(move |ang: f64| (big_r + r) * (ang * TAU).sin() - r * (k * ang * TAU).sin()),
+            z: zero(),
+        }
+    }
+
+    /// Creates a new spiral on the unit sphere.
+    ///
+    /// Winds from the north pole to the south pole around the z-axis `turns` times.
+    pub fn spherical_spiral(turns: f64) -> Self {
+        let pi = 0.5 * TAU;
+        Point {
+            x: Arc::new(move |t: f64| (t * pi).sin() * (t * turns * TAU).cos()),
+            y: Arc::new(move |t: f64| (t * pi).sin() * (t * turns * TAU).sin()),
+            z: Arc::new(move |t: f64| (t * pi).cos()),
+        }
+    }
+
     /// Creates a new zig-zag function in the xy-plane.
     pub fn zig_zag() -> Self {
         Point {
@@ -256,6 +795,275 @@ impl PointFunc<f64> {
             z: Arc::new(move |v| v),
         }
     }
+
+    /// Reconstructs a unit speed curve of length 1 from its curvature and torsion.
+    ///
+    /// Integrates the Frenet-Serret equations over `[0, 1]` in `n` steps with Runge-Kutta 4,
+    /// starting at the origin with tangent along x and normal along y.
+    /// The curve linearly interpolates between the steps, like `memoize`.
+    /// Panics if `n` is zero.
+    pub fn from_curvature_torsion(kappa: Func<f64, f64>, tau: Func<f64, f64>, n: usize) -> Self {
+        assert!(n >= 1, "from_curvature_torsion requires at least 1 step");
+        // State of position, tangent, normal and binormal.
+        type State = [Point; 4];
+        let deriv = |s: f64, [_, t, n, b]: State| -> State {
+            let k = kappa(s);
+            let w = tau(s);
+            [t, n * k, t * -k + b * w, n * -w]
+        };
+        let step = |a: State, d: State, h: f64| -> State {
+            [a[0] + d[0] * h, a[1] + d[1] * h, a[2] + d[2] * h, a[3] + d[3] * h]
+        };
+        let h = 1.0 / n as f64;
+        let mut state: State = [
+            Point::origin(),
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(0.0, 1.0, 0.0),
+            Point::new(0.0, 0.0, 1.0),
+        ];
+        let mut samples = Vec::with_capacity(n + 1);
+        samples.push(state[0]);
+        for i in 0..n {
+            let s = i as f64 * h;
+            let k1 = deriv(s, state);
+            let k2 = deriv(s + 0.5 * h, step(state, k1, 0.5 * h));
+            let k3 = deriv(s + 0.5 * h, step(state, k2, 0.5 * h));
+            let k4 = deriv(s + h, step(state, k3, h));
+            let d: State = [0, 1, 2, 3].map(|j| (k1[j] + (k2[j] + k3[j]) * 2.0 + k4[j]) * (1.0 / 6.0));
+            state = step(state, d, h);
+            samples.push(state[0]);
+        }
+        let samples = Arc::new(samples);
+        PointFunc::from_fn(move |t: f64| {
+            let s = t.clamp(0.0, 1.0) * n as f64;
+            let i = (s.floor() as usize).min(n - 1);
+            let u = s - i as f64;
+            samples[i] + (samples[i + 1] - samples[i]) * u
+        })
+    }
+
+    /// Precomputes `n` samples over `[0, 1]` and linearly interpolates between them.
+    ///
+    /// Parameters outside `[0, 1]` are clamped to the ends.
+    /// Panics if `n` is less than 2.
+    pub fn memoize(self, n: usize) -> Self {
+        assert!(n >= 2, "memoize requires at least 2 samples");
+        fn table(f: Func<f64, f64>, n: usize) -> Func<f64, f64> {
+            let samples: Vec<f64> = (0..n).map(|i| f(i as f64 / (n - 1) as f64)).collect();
+            Arc::new(move |t| {
+                let s = t.clamp(0.0, 1.0) * (n - 1) as f64;
+                let i = (s.floor() as usize).min(n - 2);
+                let u = s - i as f64;
+                samples[i] + (samples[i + 1] - samples[i]) * u
+            })
+        }
+        Point {
+            x: table(self.x, n),
+            y: table(self.y, n),
+            z: table(self.z, n),
+        }
+    }
+
+    /// Clamps the parameter into `[lo, hi]` before evaluating.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo` is greater than `hi` or either is NaN.
+    pub fn clamp_domain(self, lo: f64, hi: f64) -> Self {
+        assert!(lo <= hi, "clamp_domain requires lo <= hi");
+        self.map(move |t: f64| t.clamp(lo, hi))
+    }
+
+    /// Linearly maps a parameter in `to` onto `from` before evaluating.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `to` range is empty, i.e. `to.0 == to.1`.
+    pub fn remap_domain(self, from: (f64, f64), to: (f64, f64)) -> Self {
+        assert!(to.0 != to.1, "remap_domain requires a non-empty `to` range");
+        let s = (from.1 - from.0) / (to.1 - to.0);
+        self.map(move |t: f64| from.0 + (t - to.0) * s)
+    }
+
+    /// Reverses the direction of the parameter over `[0, 1]`.
+    pub fn reverse(self) -> Self {
+        self.map(move |t: f64| 1.0 - t)
+    }
+
+    /// Wraps the parameter periodically into `[0, 1)` before evaluating.
+    pub fn wrap_domain(self) -> Self {
+        self.map(move |t: f64| t.rem_euclid(1.0))
+    }
+
+    /// Joins two curves end to end.
+    ///
+    /// Evaluates `self` on `[0, 0.5)` and `other` on `[0.5, 1]`,
+    /// rescaling the parameter of each to `[0, 1]`.
+    pub fn concat(self, other: PointFunc<f64>) -> Self {
+        fn join(a: Func<f64, f64>, b: Func<f64, f64>) -> Func<f64, f64> {
+            Arc::new(move |t| if t < 0.5 {a(2.0 * t)} else {b(2.0 * t - 1.0)})
+        }
+        Point {
+            x: join(self.x, other.x),
+            y: join(self.y, other.y),
+            z: join(self.z, other.z),
+        }
+    }
+
+    /// Returns the Frenet frame `(tangent, normal, binormal)` at parameter `t`.
+    ///
+    /// Uses central differences with step `eps`.
+    /// Where the curvature `|d × dd| / |d|³` is below what rounding in the differences
+    /// can resolve, the curve is treated as straight and a fixed normal is built
+    /// from the tangent and the coordinate axis least aligned with it,
+    /// such that the frame does not twist along straight segments.
+    pub fn frenet_frame(&self, t: f64, eps: f64) -> (Point, Point, Point) {
+        let p0 = self.call(t - eps);
+        let p1 = self.call(t);
+        let p2 = self.call(t + eps);
+        let d1 = (p2 - p0) * (0.5 / eps);
+        let d2 = (p2 - p1 * 2.0 + p0) * (1.0 / (eps * eps));
+        let tangent = d1.normalize();
+        let speed2 = d1.dot(d1);
+        let curvature = d1.cross(d2).norm() / (speed2 * speed2.sqrt());
+        // Rounding error of the second difference relative to the magnitude of the point.
+        let noise = 1000.0 * f64::EPSILON * (p1.norm() + 1.0) / (eps * eps * speed2);
+        let normal = if curvature > noise.max(0.000001) {
+            (d2 - tangent * d2.dot(tangent)).normalize()
+        } else {
+            fallback_normal(tangent)
+        };
+        (tangent, normal, tangent.cross(normal))
+    }
+
+    /// Creates a tube surface around the curve with a varying radius.
+    ///
+    /// The first parameter moves along the curve and the second around it,
+    /// using the Frenet frame of the curve.
+    pub fn tube(self, radius: Func<f64, f64>) -> PointFunc<[f64; 2]> {
+        let f = Arc::new(move |p: [f64; 2]| {
+            let (_, n, b) = self.frenet_frame(p[0], 0.0001);
+            let ang = p[1] * TAU;
+            self.call(p[0]) + (n * ang.cos() + b * ang.sin()) * radius(p[0])
+        });
+        let fx = f.clone();
+        let fy = f.clone();
+        let fz = f;
+        Point {
+            x: Arc::new(move |p| fx(p).x),
+            y: Arc::new(move |p| fy(p).y),
+            z: Arc::new(move |p| fz(p).z),
+        }
+    }
+
+    /// Returns the parallel curve in the xy-plane at `distance` from this curve.
+    ///
+    /// Each point moves along the in-plane normal, which is the tangent rotated 90° clockwise,
+    /// pointing outwards for counter-clockwise curves.
+    /// The tangent uses a forward difference with step `eps`.
+    /// The offset curve may self-intersect where the curvature radius is less than `distance`.
+    pub fn offset(self, distance: f64, eps: f64) -> Self {
+        PointFunc::from_fn(move |t| {
+            let d = self.tangent_at(t, eps);
+            let n = Point::new(d.y, -d.x, 0.0).normalize();
+            self.call(t) + n * distance
+        })
+    }
+
+    /// Returns the forward difference derivative at a single parameter.
+    ///
+    /// This equals `diff(eps).call(t)` without building a new curve.
+    pub fn tangent_at(&self, t: f64, eps: f64) -> Point {
+        (self.call(t + eps) - self.call(t)) * (1.0 / eps)
+    }
+
+    /// Samples `n` points with their tangents over `[0, 1)`.
+    ///
+    /// Tangents use the same forward difference as `diff`,
+    /// reusing the evaluated point at each parameter.
+    pub fn sample_with_tangent(&self, n: usize, eps: f64) -> Vec<(Point, Point)> {
+        (0..n).map(|i| {
+            let t = i as f64 / n as f64;
+            let p = self.call(t);
+            let q = self.call(t + eps);
+            (p, (q - p) * (1.0 / eps))
+        }).collect()
+    }
+
+    /// Returns an iterator lazily sampling `n` points over `[0, 1)`.
+    pub fn iter_samples(&self, n: usize) -> impl Iterator<Item = Point> + '_ {
+        (0..n).map(move |i| self.call(i as f64 / n as f64))
+    }
+
+    /// Samples `n` points over `[0, 1)` into a buffer, replacing its contents.
+    ///
+    /// Reusing the buffer avoids allocating when sampling repeatedly.
+    pub fn sample_into(&self, out: &mut Vec<Point>, n: usize) {
+        out.clear();
+        out.extend(self.iter_samples(n));
+    }
+
+    /// Samples `n` points over `[0, 1)` into a flat buffer of `[x, y, z, x, y, z, ...]`.
+    pub fn sample_flat(&self, n: usize) -> Vec<f64> {
+        let mut buf = Vec::with_capacity(3 * n);
+        for i in 0..n {
+            let p = self.call(i as f64 / n as f64);
+            buf.extend_from_slice(&[p.x, p.y, p.z]);
+        }
+        buf
+    }
+
+    /// Samples points over `[0, 1]`, subdividing where the curve bends.
+    ///
+    /// An interval is split when the curve at a quarter, half or three quarters
+    /// of the interval is further than `tol` from the chord, up to `max_depth` subdivisions.
+    /// Testing several interior samples keeps symmetric curves, such as a full sine period,
+    /// from being taken as flat at the first level.
+    pub fn sample_adaptive(&self, tol: f64, max_depth: usize) -> Vec<Point> {
+        fn distance_to_segment(p: Point, a: Point, b: Point) -> f64 {
+            let ab = b - a;
+            let len2 = ab.dot(ab);
+            if len2 == 0.0 {return p.distance(a)};
+            let t = ((p - a).dot(ab) / len2).clamp(0.0, 1.0);
+            p.distance(a + ab * t)
+        }
+
+        fn subdivide(
+            f: &PointFunc<f64>,
+            (a, pa): (f64, Point),
+            (b, pb): (f64, Point),
+            tol: f64,
+            depth: usize,
+            out: &mut Vec<Point>
+        ) {
+            let m = 0.5 * (a + b);
+            let pm = f.call(m);
+            let bent = depth > 0 && [0.25, 0.5, 0.75].iter().any(|&s| {
+                let q = if s == 0.5 {pm} else {f.call(a + (b - a) * s)};
+                distance_to_segment(q, pa, pb) > tol
+            });
+            if bent {
+                subdivide(f, (a, pa), (m, pm), tol, depth - 1, out);
+                subdivide(f, (m, pm), (b, pb), tol, depth - 1, out);
+            } else {
+                out.push(pb);
+            }
+        }
+
+        let p0 = self.call(0.0);
+        let p1 = self.call(1.0);
+        let mut out = vec![p0];
+        subdivide(self, (0.0, p0), (1.0, p1), tol, max_depth, &mut out);
+        out
+    }
+
+    /// Samples `n` points over `[0, 1)` in parallel, preserving order.
+    #[cfg(feature = "rayon")]
+    pub fn sample_par(&self, n: usize) -> Vec<Point> {
+        use rayon::prelude::*;
+
+        (0..n).into_par_iter().map(|i| self.call(i as f64 / n as f64)).collect()
+    }
 }
 
 impl Diff for PointFunc<f64> {
@@ -284,6 +1092,19 @@ impl<T: 'static + Copy> From<PointFunc<(T, T)>> for PointFunc<[T; 2]> {
     }
 }
 
+impl<T: 'static + Copy> From<PointFunc<[T; 2]>> for PointFunc<(T, T)> {
+    fn from(val: PointFunc<[T; 2]>) -> Self {
+        let fx = val.x;
+        let fy = val.y;
+        let fz = val.z;
+        Point {
+            x: Arc::new(move |(a, b)| fx([a, b])),
+            y: Arc::new(move |(a, b)| fy([a, b])),
+            z: Arc::new(move |(a, b)| fz([a, b])),
+        }
+    }
+}
+
 impl<T: Clone> Ho<Arg<T>> for Point {
     type Fun = PointFunc<T>;
 }
@@ -306,17 +1127,46 @@ impl From<Point> for [f64; 3] {
     }
 }
 
-impl Into<Point> for [f64; 3] {
-    fn into(self) -> Point {
-        Point {x: self[0], y: self[1], z: self[2]}
+impl From<[f64; 3]> for Point {
+    fn from(val: [f64; 3]) -> Point {
+        Point {x: val[0], y: val[1], z: val[2]}
     }
 }
 
-impl<T> Into<PointFunc<T>> for [f64; 3] {
-    fn into(self) -> PointFunc<T> {
-        let x = self[0];
-        let y = self[1];
-        let z = self[2];
+#[cfg(feature = "glam")]
+impl From<Point> for glam::DVec3 {
+    fn from(val: Point) -> glam::DVec3 {
+        glam::DVec3::new(val.x, val.y, val.z)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::DVec3> for Point {
+    fn from(val: glam::DVec3) -> Point {
+        Point {x: val.x, y: val.y, z: val.z}
+    }
+}
+
+/// Lossy conversion, since coordinates are rounded to the nearest `f32`.
+#[cfg(feature = "glam")]
+impl From<Point> for glam::Vec3 {
+    fn from(val: Point) -> glam::Vec3 {
+        glam::Vec3::new(val.x as f32, val.y as f32, val.z as f32)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::Vec3> for Point {
+    fn from(val: glam::Vec3) -> Point {
+        Point {x: val.x as f64, y: val.y as f64, z: val.z as f64}
+    }
+}
+
+impl<T> From<[f64; 3]> for PointFunc<T> {
+    fn from(val: [f64; 3]) -> PointFunc<T> {
+        let x = val[0];
+        let y = val[1];
+        let z = val[2];
         Point {
             x: Arc::new(move |_| x),
             y: Arc::new(move |_| y),
@@ -349,6 +1199,14 @@ impl Add for Point {
     }
 }
 
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+    }
+}
+
 impl<T: 'static + Copy> Add for PointFunc<T> {
     type Output = Self;
 
@@ -414,6 +1272,23 @@ impl<T: 'static + Copy> Add<Func<T, Point>> for PointFunc<T> {
     }
 }
 
+impl<T: 'static> Add<Func<T, Point>> for Point {
+    type Output = PointFunc<T>;
+    fn add(self, other: Func<T, Point>) -> PointFunc<T> {
+        let x = self.x;
+        let y = self.y;
+        let z = self.z;
+        let ox = other.clone();
+        let oy = other.clone();
+        let oz = other;
+        Point {
+            x: Arc::new(move |t: T| x + ox(t).x),
+            y: Arc::new(move |t: T| y + oy(t).y),
+            z: Arc::new(move |t: T| z + oz(t).z),
+        }
+    }
+}
+
 impl Sub<f64> for Point {
     type Output = Self;
     fn sub(self, val: f64) -> Self {
@@ -436,6 +1311,14 @@ impl Sub for Point {
     }
 }
 
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+    }
+}
+
 impl<T: 'static + Copy> Sub for PointFunc<T> {
     type Output = Self;
     fn sub(self, other: Self) -> Self {
@@ -475,9 +1358,43 @@ impl<T: 'static, U: Into<Point>> Sub<U> for PointFunc<T> {
         let by = other.y;
         let bz = other.z;
         Point {
-            x: Arc::new(move |a: T| ax(a) - bx),
-            y: Arc::new(move |a: T| ay(a) - by),
-            z: Arc::new(move |a: T| az(a) - bz),
+            x: Arc::new(move |a: T| ax(a) - bx),
+            y: Arc::new(move |a: T| ay(a) - by),
+            z: Arc::new(move |a: T| az(a) - bz),
+        }
+    }
+}
+
+impl<T: 'static + Copy> Sub<Func<T, Point>> for PointFunc<T> {
+    type Output = PointFunc<T>;
+    fn sub(self, other: Func<T, Point>) -> PointFunc<T> {
+        let ax = self.x;
+        let ay = self.y;
+        let az = self.z;
+        let ox = other.clone();
+        let oy = other.clone();
+        let oz = other;
+        Point {
+            x: Arc::new(move |t: T| ax(t) - ox(t).x),
+            y: Arc::new(move |t: T| ay(t) - oy(t).y),
+            z: Arc::new(move |t: T| az(t) - oz(t).z),
+        }
+    }
+}
+
+impl<T: 'static> Sub<Func<T, Point>> for Point {
+    type Output = PointFunc<T>;
+    fn sub(self, other: Func<T, Point>) -> PointFunc<T> {
+        let x = self.x;
+        let y = self.y;
+        let z = self.z;
+        let ox = other.clone();
+        let oy = other.clone();
+        let oz = other;
+        Point {
+            x: Arc::new(move |t: T| x - ox(t).x),
+            y: Arc::new(move |t: T| y - oy(t).y),
+            z: Arc::new(move |t: T| z - oz(t).z),
         }
     }
 }
@@ -493,6 +1410,14 @@ impl Mul<f64> for Point {
     }
 }
 
+impl MulAssign<f64> for Point {
+    fn mul_assign(&mut self, other: f64) {
+        self.x *= other;
+        self.y *= other;
+        self.z *= other;
+    }
+}
+
 impl<T: 'static> Mul<f64> for PointFunc<T> {
     type Output = Self;
     fn mul(self, other: f64) -> Self {
@@ -589,6 +1514,23 @@ impl<T: 'static> Div<f64> for PointFunc<T> {
     }
 }
 
+impl<T: 'static> Div<PointFunc<T>> for Point {
+    type Output = PointFunc<T>;
+    fn div(self, other: PointFunc<T>) -> PointFunc<T> {
+        let x = self.x;
+        let y = self.y;
+        let z = self.z;
+        let ox = other.x;
+        let oy = other.y;
+        let oz = other.z;
+        Point {
+            x: Arc::new(move |t| x / ox(t)),
+            y: Arc::new(move |t| y / oy(t)),
+            z: Arc::new(move |t| z / oz(t)),
+        }
+    }
+}
+
 impl<T: 'static + Copy> Div<Func<T, f64>> for PointFunc<T> {
     type Output = Self;
     fn div(self, other: Func<T, f64>) -> Self {
@@ -639,6 +1581,27 @@ mod tests {
         assert_eq!(q.y, 2.0);
     }
 
+    #[test]
+    fn from_fn() {
+        let a = PointFunc::from_fn(|t: f64| {
+            let ang = t * TAU;
+            Point::new(ang.cos(), ang.sin(), 0.0)
+        });
+        let b = Point::circle();
+        for &t in &[0.0, 0.25, 0.6] {
+            assert_eq!(a.call(t), b.call(t));
+        }
+    }
+
+    #[test]
+    fn from_coords() {
+        let a = PointFunc::from_coords(|t: f64| t, |_| 0.0, |_| 0.0);
+        let b = Point::x();
+        for &t in &[0.0, 0.5, 2.0] {
+            assert_eq!(a.call(t), b.call(t));
+        }
+    }
+
     #[test]
     fn cylinder() {
         let p: PointFunc<_> = Point {
@@ -687,6 +1650,31 @@ mod tests {
         assert_eq!((q.y)(0.25), 0.5);
     }
 
+    #[test]
+    fn fix_args() {
+        let p: PointFunc<_> = Point {
+            x: Arc::new(move |(ang, _height): (f64, f64)| ang.cos()),
+            y: Arc::new(move |(ang, _height): (f64, f64)| ang.sin()),
+            z: Arc::new(move |(_, height): (f64, f64)| height),
+        };
+        let line = p.clone().fix_first(0.0);
+        assert_eq!(line.call(0.0), Point::new(1.0, 0.0, 0.0));
+        assert_eq!(line.call(2.0), Point::new(1.0, 0.0, 2.0));
+        let ring = p.fix_second(3.0);
+        assert_eq!(ring.call(0.0), Point::new(1.0, 0.0, 3.0));
+        let q = Point::circle().lift_right::<f64>().fix_second(5.0);
+        assert_eq!(q.call(0.25), Point::circle().call(0.25));
+    }
+
+    #[test]
+    fn product() {
+        let r = PointFunc::product(Point::circle_radians(), Point::z());
+        assert_eq!(r.call([0.0, 0.0]), Point::new(1.0, 0.0, 0.0));
+        assert_eq!(r.call([0.0, 1.0]), Point::new(1.0, 0.0, 1.0));
+        let a = r.call([1.0, 2.0]);
+        assert_eq!(a, Point::new(1.0f64.cos(), 1.0f64.sin(), 2.0));
+    }
+
     #[test]
     fn disc() {
         let p = Point::circle();
@@ -912,4 +1900,669 @@ mod tests {
         let b: [f64; 3] = a.into();
         assert_eq!(b, [0.0, 1.0, 2.0]);
     }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam_dvec3() {
+        let a = Point {x: 0.1, y: 1.0, z: 2.0};
+        let b: glam::DVec3 = a.into();
+        assert_eq!(b, glam::DVec3::new(0.1, 1.0, 2.0));
+        let c: Point = b.into();
+        assert_eq!(c, a);
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam_vec3() {
+        let a = Point {x: 0.1, y: 1.0, z: 2.0};
+        let b: glam::Vec3 = a.into();
+        assert_eq!(b, glam::Vec3::new(0.1, 1.0, 2.0));
+        let c: Point = b.into();
+        assert!((c.x - 0.1).abs() < 0.0000001);
+        assert_eq!(c.y, 1.0);
+        assert_eq!(c.z, 2.0);
+    }
+
+    #[cfg(feature = "vecmath")]
+    #[test]
+    fn transform() {
+        let mut m = vecmath::mat4_id();
+        m[3] = [1.0, 2.0, 3.0, 1.0];
+        let a: Point = Point {x: 0.0, y: 0.0, z: 0.0};
+        assert_eq!(a.transform(m), Point {x: 1.0, y: 2.0, z: 3.0});
+    }
+
+    #[cfg(feature = "vecmath")]
+    #[test]
+    fn transform_func() {
+        let mut m = vecmath::mat4_id();
+        m[3] = [1.0, 0.0, 2.0, 1.0];
+        let a = Point::circle().transform(m);
+        assert_eq!(a.call(0.0), Point {x: 2.0, y: 0.0, z: 2.0});
+        let a2 = a.call(0.25);
+        assert!((a2.x - 1.0).abs() < 0.0000001);
+        assert_eq!(a2.y, 1.0);
+        assert_eq!(a2.z, 2.0);
+
+        // Rows and the `w` divide match the point transform for a general matrix.
+        let m = [
+            [0.5, 1.0, -2.0, 0.1],
+            [3.0, -1.0, 0.0, 0.2],
+            [0.0, 2.0, 1.0, -0.3],
+            [1.0, 4.0, -1.0, 2.0],
+        ];
+        let a = Point::circle() + [0.0, 0.0, 1.0];
+        let b = a.clone().transform(m);
+        for &t in &[0.0, 0.3, 0.8] {
+            assert!(b.call(t).approx_eq(&a.call(t).transform(m), 1e-12));
+        }
+    }
+
+    #[test]
+    fn from_curvature_torsion() {
+        let a = PointFunc::from_curvature_torsion(k(TAU), k(0.0), 1000);
+        let r = 1.0 / TAU;
+        let center = Point::new(0.0, r, 0.0);
+        for i in 0..=20 {
+            let p = a.call(i as f64 / 20.0);
+            assert!((p.distance(center) - r).abs() < 1e-5);
+            assert!(p.z.abs() < 1e-12);
+        }
+        assert!(a.call(1.0).distance(Point::origin()) < 1e-9);
+    }
+
+    #[test]
+    fn memoize() {
+        let a = Point::circle();
+        let b = Point::circle().memoize(1000);
+        for i in 0..=100 {
+            let t = i as f64 / 100.0;
+            let a1 = a.call(t);
+            let b1 = b.call(t);
+            assert!((a1.x - b1.x).abs() < 0.00001);
+            assert!((a1.y - b1.y).abs() < 0.00001);
+            assert_eq!(b1.z, 0.0);
+        }
+        assert_eq!(b.call(-1.0), b.call(0.0));
+        assert_eq!(b.call(2.0), b.call(1.0));
+    }
+
+    #[test]
+    fn sample_with_tangent() {
+        let a = Point::circle().sample_with_tangent(16, 0.00000001);
+        assert_eq!(a.len(), 16);
+        for (p, dp) in a {
+            assert!(p.dot(dp).abs() < 0.000001);
+            assert!((dp.norm() - TAU).abs() < 0.00001);
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn sample_par() {
+        let a = Point::circle();
+        let b = a.sample_par(1000);
+        let c: Vec<Point> = (0..1000).map(|i| a.call(i as f64 / 1000.0)).collect();
+        assert_eq!(b, c);
+    }
+
+    #[test]
+    fn sample_grid() {
+        let a = Point::ground_plane().sample_grid([3, 5]);
+        assert_eq!(a.len(), 3);
+        for row in &a {
+            assert_eq!(row.len(), 5);
+        }
+        assert_eq!(a[0][0], Point {x: 0.0, y: 0.0, z: 0.0});
+        assert_eq!(a[0][4], Point {x: 0.0, y: 1.0, z: 0.0});
+        assert_eq!(a[2][0], Point {x: 1.0, y: 0.0, z: 0.0});
+        assert_eq!(a[2][4], Point {x: 1.0, y: 1.0, z: 0.0});
+        assert_eq!(a[1][2], Point {x: 0.5, y: 0.5, z: 0.0});
+    }
+
+    #[test]
+    fn mirror() {
+        let a = Point::circle() + [0.0, 0.0, 1.0];
+        let b = a.clone().mirror_x();
+        let c = a.clone().mirror_y();
+        let d = a.clone().mirror_z();
+        for &t in &[0.0, 0.1, 0.25, 0.6] {
+            let p = a.call(t);
+            assert_eq!(b.call(t), Point {x: -p.x, y: p.y, z: p.z});
+            assert_eq!(c.call(t), Point {x: p.x, y: -p.y, z: p.z});
+            assert_eq!(d.call(t), Point {x: p.x, y: p.y, z: -p.z});
+        }
+    }
+
+    #[test]
+    fn clamp_domain() {
+        let a = (Point::x() * 2.0).clamp_domain(0.0, 1.0);
+        assert_eq!(a.call(2.0), a.call(1.0));
+        assert_eq!(a.call(-1.0), a.call(0.0));
+        assert_eq!(a.call(0.5).x, 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_domain_inverted() {
+        let _ = Point::x().clamp_domain(1.0, 0.0);
+    }
+
+    #[test]
+    fn remap_domain() {
+        let a = Point::circle();
+        let b = Point::circle().remap_domain((0.0, 1.0), (0.0, 360.0));
+        assert_eq!(b.call(0.0), a.call(0.0));
+        assert_eq!(b.call(90.0), a.call(0.25));
+        assert_eq!(b.call(180.0), a.call(0.5));
+        assert_eq!(b.call(360.0), a.call(1.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn remap_domain_empty() {
+        let _ = Point::circle().remap_domain((0.0, 1.0), (2.0, 2.0));
+    }
+
+    #[test]
+    fn concat() {
+        let p: Point = [0.0, 0.0, 0.0].into();
+        let q: Point = [1.0, 0.0, 0.0].into();
+        let r: Point = [1.0, 1.0, 0.0].into();
+        let a: PointFunc<f64> = line(&p, &q, &id());
+        let b: PointFunc<f64> = line(&q, &r, &id());
+        let c = a.concat(b);
+        assert_eq!(c.call(0.0), p);
+        assert_eq!(c.call(0.25), [0.5, 0.0, 0.0].into());
+        assert_eq!(c.call(0.5), q);
+        assert!(c.call(0.4999999).distance(q) < 0.000001);
+        assert_eq!(c.call(0.75), [1.0, 0.5, 0.0].into());
+        assert_eq!(c.call(1.0), r);
+    }
+
+    #[test]
+    fn call_many() {
+        let a = Point::circle();
+        let ts = [0.0, 0.25, 0.5, 0.75];
+        let b = a.call_many(&ts);
+        assert_eq!(b.len(), 4);
+        for (&t, &p) in ts.iter().zip(b.iter()) {
+            assert_eq!(p, a.call(t));
+        }
+        assert!(a.call_many(&[]).is_empty());
+    }
+
+    #[test]
+    fn sample_adaptive() {
+        let a = Point::x() + [0.0, 1.0, 2.0];
+        let b = a.sample_adaptive(0.001, 10);
+        assert_eq!(b, vec![a.call(0.0), a.call(1.0)]);
+
+        let c = Point::circle().sample_adaptive(0.001, 10);
+        assert!(c.len() > 50);
+        assert!(c[0].distance(c[c.len() - 1]) < 0.000001);
+        for w in c.windows(2) {
+            assert!(w[0].distance(w[1]) < 0.2);
+        }
+    }
+
+    #[test]
+    fn sample_adaptive_sine() {
+        let a = PointFunc::from_coords(|t: f64| t, |t: f64| (t * TAU).sin(), |_| 0.0);
+        let b = a.sample_adaptive(0.001, 10);
+        assert!(b.len() > 20);
+        assert_eq!(b[0], a.call(0.0));
+        assert!(b[b.len() - 1].approx_eq(&a.call(1.0), 1e-12));
+    }
+
+    #[test]
+    fn sample_adaptive_reparameterized_line() {
+        let a = PointFunc::from_coords(|t: f64| t * t, |_| 0.0, |_| 0.0);
+        let b = a.sample_adaptive(0.001, 10);
+        assert_eq!(b, vec![a.call(0.0), a.call(1.0)]);
+    }
+
+    #[test]
+    fn approx_eq() {
+        let a: Point = [1.0, 2.0, 3.0].into();
+        let b: Point = [1.0 + 1e-12, 2.0 - 1e-12, 3.0].into();
+        let c: Point = [1.0, 2.1, 3.0].into();
+        assert!(a.approx_eq(&b, 1e-10));
+        assert!(!a.approx_eq(&c, 1e-10));
+        assert!(a.approx_eq(&c, 0.2));
+    }
+
+    #[test]
+    fn is_finite() {
+        let a: Point = [1.0, 2.0, 3.0].into();
+        let b: Point = [1.0, f64::NAN, f64::INFINITY].into();
+        assert!(a.is_finite());
+        assert!(!b.is_finite());
+        assert_eq!(a.sanitize([0.0; 3].into()), a);
+        assert_eq!(b.sanitize([7.0, 8.0, 9.0].into()), [1.0, 8.0, 9.0].into());
+    }
+
+    #[test]
+    fn sample_flat() {
+        let a = Point::circle() + [0.0, 0.0, 2.0];
+        let b = a.sample_flat(8);
+        assert_eq!(b.len(), 24);
+        assert_eq!(&b[..3], &[1.0, 0.0, 2.0]);
+        let p = a.call(0.25);
+        assert_eq!(&b[6..9], &[p.x, p.y, p.z]);
+    }
+
+    #[test]
+    fn into_tuple() {
+        let p: PointFunc<[f64; 2]> = Point::ground_plane();
+        let q: PointFunc<(f64, f64)> = p.clone().into();
+        let r: PointFunc<[f64; 2]> = q.clone().into();
+        assert_eq!(q.call((0.25, 0.5)), p.call([0.25, 0.5]));
+        assert_eq!(r.call([0.25, 0.5]), p.call([0.25, 0.5]));
+    }
+
+    #[test]
+    fn array_arg() {
+        let p: PointFunc<[f64; 4]> = Point {
+            x: Arc::new(move |a: [f64; 4]| a[0] + a[3]),
+            y: Arc::new(move |a: [f64; 4]| a[1]),
+            z: Arc::new(move |a: [f64; 4]| a[2] * a[3]),
+        };
+        assert_eq!(p.call([1.0, 2.0, 3.0, 4.0]), [5.0, 2.0, 12.0].into());
+        assert_eq!(p.call_many(&[[0.0; 4], [1.0; 4]]), vec![
+            [0.0, 0.0, 0.0].into(),
+            [2.0, 1.0, 1.0].into(),
+        ]);
+
+        let q: PointFunc<f64> = p.map(|t: f64| [t, t, t, 2.0]);
+        assert_eq!(q.call(1.0), [3.0, 1.0, 2.0].into());
+
+        let r: PointFunc<[f64; 4]> = [1.0, 2.0, 3.0].into();
+        assert_eq!(r.call([0.0; 4]), [1.0, 2.0, 3.0].into());
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(Point::new(1.0, 2.0, 3.0), Point {x: 1.0, y: 2.0, z: 3.0});
+        assert_eq!(Point::origin(), Point {x: 0.0, y: 0.0, z: 0.0});
+    }
+
+    #[test]
+    fn constant() {
+        let p = Point::new(1.0, 2.0, 3.0);
+        let a = PointFunc::<f64>::constant(p);
+        assert_eq!(a.call(0.0), p);
+        assert_eq!(a.call(-7.5), p);
+        let b = PointFunc::<(bool, usize)>::constant(p);
+        assert_eq!(b.call((true, 3)), p);
+    }
+
+    #[test]
+    fn map_coords() {
+        let a = Point::x() + [1.0, 2.0, -3.0];
+        let b = a.map_coords(|v| v * v);
+        assert_eq!(b.call(0.0), Point::new(1.0, 4.0, 9.0));
+        assert_eq!(b.call(1.0), Point::new(4.0, 4.0, 9.0));
+    }
+
+    #[test]
+    fn map_xyz() {
+        let a = Point::circle() + [0.0, 0.0, 2.0];
+        let b = a.clone().map_xyz(|x| x, |y| y, |z| z * z + 1.0);
+        for &t in &[0.0, 0.3, 0.8] {
+            let p = a.call(t);
+            assert_eq!(b.call(t), Point::new(p.x, p.y, 5.0));
+        }
+    }
+
+    #[test]
+    fn spherical_spiral() {
+        let a = Point::spherical_spiral(5.0);
+        assert_eq!(a.call(0.0), Point::new(0.0, 0.0, 1.0));
+        assert!(a.call(1.0).approx_eq(&Point::new(0.0, 0.0, -1.0), 1e-12));
+        for &t in &[0.1, 0.45, 0.9] {
+            assert!((a.call(t).norm() - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn superellipse() {
+        let a = Point::superellipse(2.0, 1.0, 2.0);
+        let b = Point::circle().map_xyz(|x| 2.0 * x, |y| y, |z| z);
+        for i in 0..16 {
+            let t = i as f64 / 16.0;
+            assert!(a.call(t).approx_eq(&b.call(t), 1e-12));
+        }
+
+        let c = Point::superellipse(2.0, 1.0, 100.0);
+        let corner = c.call(0.125);
+        assert!(corner.x > 1.98 && corner.x <= 2.0);
+        assert!(corner.y > 0.99 && corner.y <= 1.0);
+        assert_eq!(c.call(0.0), Point::new(2.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn rose() {
+        let a = Point::rose(3.0);
+        assert_eq!(a.call(0.0), Point::new(1.0, 0.0, 0.0));
+        let n = 1000;
+        let radius: Vec<f64> = (0..=n).map(|i| {
+            let t = i as f64 / n as f64;
+            let p = a.call(t);
+            p.x * (t * TAU).cos() + p.y * (t * TAU).sin()
+        }).collect();
+        let crossings = radius.windows(2).filter(|w| w[0].signum() != w[1].signum()).count();
+        assert_eq!(crossings, 6);
+    }
+
+    #[test]
+    fn epicycloid() {
+        let a = Point::epicycloid(1.0, 1.0);
+        assert_eq!(a.call(0.0), Point::new(1.0, 0.0, 0.0));
+        assert!(a.call(0.5).approx_eq(&Point::new(-3.0, 0.0, 0.0), 1e-12));
+        let da = a.diff(0.00000001);
+        assert!(da.call(0.0).norm() < 0.00001);
+        for i in 1..100 {
+            let t = i as f64 / 100.0;
+            assert!(da.call(t).norm() > 0.1);
+        }
+    }
+
+    #[test]
+    fn tube() {
+        let a = (Point::z() * 2.0).tube(k(0.5));
+        for i in 0..=4 {
+            for j in 0..8 {
+                let u = i as f64 / 4.0;
+                let p = a.call([u, j as f64 / 8.0]);
+                assert!((p.x.hypot(p.y) - 0.5).abs() < 1e-9);
+                assert!((p.z - 2.0 * u).abs() < 1e-9);
+            }
+        }
+
+        let (t, n, b) = Point::circle().frenet_frame(0.0, 0.0001);
+        assert!(t.approx_eq(&Point::new(0.0, 1.0, 0.0), 1e-6));
+        assert!(n.approx_eq(&Point::new(-1.0, 0.0, 0.0), 1e-6));
+        assert!(b.approx_eq(&Point::new(0.0, 0.0, 1.0), 1e-6));
+    }
+
+    #[test]
+    fn frenet_frame_offset_line() {
+        let a = PointFunc::from_coords(
+            |t: f64| 1234.5 + 3.3 * t,
+            |t: f64| 500.1 + 7.7 * t,
+            |t: f64| 2.9 * t,
+        );
+        let (t0, n0, _) = a.frenet_frame(0.0, 0.0001);
+        for i in 0..=10 {
+            let (t, n, b) = a.frenet_frame(i as f64 / 10.0, 0.0001);
+            assert!(t.approx_eq(&t0, 1e-6));
+            assert!(n.approx_eq(&n0, 1e-6));
+            assert!(n.dot(t).abs() < 1e-9);
+            assert!((b.norm() - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn iter_samples() {
+        let a = Point::circle();
+        assert_eq!(a.iter_samples(10).count(), 10);
+        for (i, p) in a.iter_samples(10).enumerate() {
+            assert_eq!(p, a.call(i as f64 / 10.0));
+        }
+    }
+
+    #[test]
+    fn sample_into() {
+        let a = Point::circle();
+        let mut buf = vec![];
+        a.sample_into(&mut buf, 10);
+        assert_eq!(buf, a.iter_samples(10).collect::<Vec<_>>());
+        a.sample_into(&mut buf, 4);
+        assert_eq!(buf.len(), 4);
+        assert_eq!(buf, a.iter_samples(4).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn sample_grid_into() {
+        let a = PointFunc::ground_plane();
+        let mut buf = vec![];
+        a.sample_grid_into(&mut buf, [3, 4]);
+        let expected: Vec<Point> = a.sample_grid([3, 4]).into_iter().flatten().collect();
+        assert_eq!(buf, expected);
+        a.sample_grid_into(&mut buf, [2, 2]);
+        assert_eq!(buf.len(), 4);
+        assert_eq!(buf[3], a.call([1.0, 1.0]));
+    }
+
+    #[test]
+    fn offset() {
+        let a = Point::circle().offset(0.5, 1e-7);
+        for &t in &[0.0, 0.1, 0.5, 0.8] {
+            let p = a.call(t);
+            assert!((p.distance(Point::origin()) - 1.5).abs() < 1e-6);
+            assert_eq!(p.z, 0.0);
+        }
+    }
+
+    #[test]
+    fn into_array_fn() {
+        let a = Point::circle();
+        let f = a.clone().into_array_fn();
+        for &t in &[0.0, 0.3, 0.75] {
+            let p = a.call(t);
+            assert_eq!(f(t), [p.x, p.y, p.z]);
+        }
+    }
+
+    #[test]
+    fn dot_cross_mixed() {
+        let a = Point::new(1.0, 0.0, 0.0);
+        let c = Point::circle();
+        let f = a.dot(c.clone());
+        let g = c.clone().dot(a);
+        for &t in &[0.0, 0.25, 0.4] {
+            assert_eq!(f(t), c.call(t).x);
+            assert_eq!(g(t), c.call(t).x);
+        }
+        let z = Point::new(0.0, 0.0, 1.0);
+        assert_eq!(z.cross(c.clone()).call(0.0), Point::new(0.0, 1.0, 0.0));
+        assert_eq!(c.cross(z).call(0.0), Point::new(0.0, -1.0, 0.0));
+    }
+
+    #[test]
+    fn map_indexed() {
+        let a = Point::x().map_indexed(|i, t: f64| t + i as f64 * 0.5);
+        assert_eq!(a.call((0, 0.25)), Point::new(0.25, 0.0, 0.0));
+        assert_eq!(a.call((2, 0.25)), Point::new(1.25, 0.0, 0.0));
+        let n = 4;
+        let xs: Vec<f64> = (0..n).map(|i| a.call((i, i as f64 / n as f64)).x).collect();
+        assert_eq!(xs, vec![0.0, 0.75, 1.5, 2.25]);
+    }
+
+    #[test]
+    fn split_surface() {
+        let a = PointFunc::product(Point::circle(), Point::z());
+        let (b, c) = a.clone().split_u(0.25);
+        let n = [5, 3];
+        let gb = b.sample_grid(n);
+        let gc = c.sample_grid(n);
+        for j in 0..n[1] {
+            let v = j as f64 / 2.0;
+            for i in 0..n[0] {
+                let u = i as f64 / 4.0;
+                assert!(gb[i][j].approx_eq(&a.call([0.25 * u, v]), 1e-12));
+                assert!(gc[i][j].approx_eq(&a.call([0.25 + 0.75 * u, v]), 1e-12));
+            }
+            assert_eq!(gb[4][j], gc[0][j]);
+        }
+        let (b, c) = a.clone().split_v(0.5);
+        assert_eq!(b.call([0.3, 1.0]), a.call([0.3, 0.5]));
+        assert_eq!(c.call([0.3, 0.0]), a.call([0.3, 0.5]));
+        assert_eq!(c.call([0.3, 1.0]), a.call([0.3, 1.0]));
+    }
+
+    #[test]
+    fn min_max() {
+        let a = Point::new(-1.0, 2.0, -3.0);
+        let b = Point::new(1.0, -2.0, -4.0);
+        assert_eq!(a.min(b), Point::new(-1.0, -2.0, -4.0));
+        assert_eq!(a.max(b), Point::new(1.0, 2.0, -3.0));
+        assert_eq!(a.min(a), a);
+    }
+
+    #[test]
+    fn clamp_box() {
+        let min = Point::new(0.0, 0.0, 0.0);
+        let max = Point::new(1.0, 2.0, 3.0);
+        assert_eq!(Point::new(0.5, 3.0, 1.0).clamp_box(min, max), Point::new(0.5, 2.0, 1.0));
+        assert_eq!(Point::new(-1.0, 1.0, 4.0).clamp_box(min, max), Point::new(0.0, 1.0, 3.0));
+        assert_eq!(Point::new(0.5, 1.0, 1.0).clamp_box(min, max), Point::new(0.5, 1.0, 1.0));
+    }
+
+    #[test]
+    fn surface_normal() {
+        let profile: PointFunc<f64> = Point {
+            x: Arc::new(move |t: f64| (t * 0.5 * TAU).sin()),
+            y: zero(),
+            z: Arc::new(move |t: f64| (t * 0.5 * TAU).cos()),
+        };
+        let sphere = revolve(&profile) * 2.0;
+        let normal = sphere.surface_normal(0.0000001);
+        for i in 1..8 {
+            for j in 0..8 {
+                let p = [i as f64 / 8.0, j as f64 / 8.0];
+                let n = normal.call(p);
+                assert!(n.approx_eq(&sphere.call(p).normalize(), 0.000001));
+            }
+        }
+
+        let n = Point::ground_plane().surface_normal(0.001).call([0.5, 0.5]);
+        assert_eq!(n, Point::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn tangent_basis() {
+        let a = Point::ground_plane() * 2.0 + [0.0, 0.0, 1.0];
+        let (tu, tv) = a.tangent_basis(0.001);
+        let n = a.surface_normal(0.001);
+        let p = [0.3, 0.6];
+        let (tu, tv, n) = (tu.call(p), tv.call(p), n.call(p));
+        assert_eq!(tu, Point::new(1.0, 0.0, 0.0));
+        assert_eq!(tv, Point::new(0.0, 1.0, 0.0));
+        assert_eq!(n, Point::new(0.0, 0.0, 1.0));
+        assert!(tu.cross(tv).dot(n) > 0.0);
+    }
+
+    #[test]
+    fn reverse() {
+        let a = Point::circle();
+        let b = Point::circle().reverse();
+        assert_eq!(b.call(0.0), a.call(1.0));
+        assert_eq!(b.call(1.0), a.call(0.0));
+        assert_eq!(b.call(0.25), a.call(0.75));
+    }
+
+    #[test]
+    fn wrap_domain() {
+        let a = Point::x() * 2.0;
+        let b = a.clone().wrap_domain();
+        assert_eq!(b.call(0.5), a.call(0.5));
+        assert_eq!(b.call(1.5), a.call(0.5));
+        assert_eq!(b.call(-0.25), a.call(0.75));
+        assert_eq!(b.call(1.0), a.call(0.0));
+    }
+
+    #[test]
+    fn distance_to_line() {
+        let a = Point::new(0.0, 0.0, 1.0);
+        let b = Point::new(2.0, 0.0, 1.0);
+        assert_eq!(Point::new(1.0, 3.0, 1.0).distance_to_line(a, b), 3.0);
+        assert_eq!(Point::new(-5.0, 0.0, 5.0).distance_to_line(a, b), 4.0);
+        assert_eq!(Point::new(1.0, 0.0, 1.0).distance_to_line(a, b), 0.0);
+        assert_eq!(Point::new(3.0, 4.0, 1.0).distance_to_line(a, a), 5.0);
+    }
+
+    #[test]
+    fn div_point_func() {
+        let a = Point::new(1.0, 2.0, 3.0);
+        let b = Point::x() + [1.0, 2.0, 0.0];
+        let c = a / b;
+        let c1 = c.call(1.0);
+        assert_eq!(c1.x, 0.5);
+        assert_eq!(c1.y, 1.0);
+        assert_eq!(c1.z, f64::INFINITY);
+    }
+
+    #[test]
+    fn point_valued_func() {
+        let f: Func<f64, Point> = Arc::new(move |t| Point::new(t, 2.0 * t, 1.0));
+        let a = Point::circle();
+        let b = Point::new(1.0, 2.0, 3.0);
+
+        let c = a.clone() + f.clone();
+        let d = a.clone() - f.clone();
+        let e = b + f.clone();
+        let g = b - f.clone();
+        for &t in &[0.0, 0.25, 0.5] {
+            assert_eq!(c.call(t), a.call(t) + f(t));
+            assert_eq!(d.call(t), a.call(t) - f(t));
+            assert_eq!(e.call(t), b + f(t));
+            assert_eq!(g.call(t), b - f(t));
+        }
+    }
+
+    #[test]
+    fn assign_ops() {
+        let mut a = Point::new(1.0, 2.0, 3.0);
+        a += Point::new(1.0, 1.0, 1.0);
+        assert_eq!(a, Point::new(2.0, 3.0, 4.0));
+        a -= Point::new(0.5, 0.5, 0.5);
+        assert_eq!(a, Point::new(1.5, 2.5, 3.5));
+        a *= 2.0;
+        assert_eq!(a, Point::new(3.0, 5.0, 7.0));
+    }
+
+    #[test]
+    fn index() {
+        let mut a = Point::new(1.0, 2.0, 3.0);
+        assert_eq!(a[0], 1.0);
+        assert_eq!(a[1], 2.0);
+        assert_eq!(a[2], 3.0);
+        for i in 0..3 {
+            a[i] *= 10.0;
+        }
+        assert_eq!(a, Point::new(10.0, 20.0, 30.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds() {
+        let a = Point::new(1.0, 2.0, 3.0);
+        let _ = a[3];
+    }
+
+    #[test]
+    fn into_iter() {
+        let a = Point::new(1.0, 2.0, 3.0);
+        let b: Vec<f64> = a.into_iter().collect();
+        assert_eq!(b, vec![1.0, 2.0, 3.0]);
+        let mut sum = 0.0;
+        for c in a {
+            sum += c;
+        }
+        assert_eq!(sum, 6.0);
+    }
+
+    #[test]
+    fn tangent_at() {
+        let a = Point::circle();
+        let eps = 0.00000001;
+        let da = a.clone().diff(eps);
+        for &t in &[0.0, 0.1, 0.25, 0.8] {
+            let p = a.tangent_at(t, eps);
+            let q = da.call(t);
+            assert!(p.approx_eq(&q, 1e-12));
+        }
+    }
 }
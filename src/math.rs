@@ -33,6 +33,64 @@ pub fn step() -> Func<f64, f64> {
     Arc::new(move |a| if a < 0.0 {0.0} else {1.0})
 }
 
+/// Returns smooth step function.
+/// This is zero below `edge0`, one above `edge1` and a Hermite blend `3t² - 2t³` in between.
+///
+/// # Panics
+///
+/// Panics if `edge0` equals `edge1`.
+pub fn smoothstep(edge0: f64, edge1: f64) -> Func<f64, f64> {
+    assert!(edge0 != edge1, "smoothstep requires edge0 != edge1");
+    Arc::new(move |a| {
+        let t = ((a - edge0) / (edge1 - edge0)).clamp(0.0, 1.0);
+        t * t * (3.0 - 2.0 * t)
+    })
+}
+
+/// Returns smoother step function.
+/// This is like `smoothstep`, but uses Ken Perlin's `6t⁵ - 15t⁴ + 10t³`
+/// such that the second derivative is zero at the edges.
+///
+/// # Panics
+///
+/// Panics if `edge0` equals `edge1`.
+pub fn smootherstep(edge0: f64, edge1: f64) -> Func<f64, f64> {
+    assert!(edge0 != edge1, "smootherstep requires edge0 != edge1");
+    Arc::new(move |a| {
+        let t = ((a - edge0) / (edge1 - edge0)).clamp(0.0, 1.0);
+        t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+    })
+}
+
+/// Returns clamp function.
+/// This limits values to the range `[lo, hi]`.
+///
+/// # Panics
+///
+/// Panics if `lo` is greater than `hi` or either is NaN.
+pub fn clamp(lo: f64, hi: f64) -> Func<f64, f64> {
+    assert!(lo <= hi, "clamp requires lo <= hi");
+    Arc::new(move |a| a.clamp(lo, hi))
+}
+
+/// Returns gaussian bump function.
+/// This peaks with value one at `center` and decays with standard deviation `sigma`.
+pub fn gaussian(center: f64, sigma: f64) -> Func<f64, f64> {
+    Arc::new(move |a| (-0.5 * ((a - center) / sigma).powi(2)).exp())
+}
+
+/// Returns sawtooth wave function.
+/// This rises linearly from zero to one over each period.
+pub fn sawtooth(period: f64) -> Func<f64, f64> {
+    Arc::new(move |a| (a / period).rem_euclid(1.0))
+}
+
+/// Returns triangle wave function.
+/// This rises from zero to one over the first half of each period and falls back over the second.
+pub fn triangle_wave(period: f64) -> Func<f64, f64> {
+    Arc::new(move |a| 1.0 - (2.0 * (a / period).rem_euclid(1.0) - 1.0).abs())
+}
+
 /// Returns floor function.
 pub fn floor() -> Func<f64, f64> {
     Arc::new(move |a| a.floor())
@@ -62,6 +120,21 @@ pub fn zip(a: Func<f64, f64>, b: Func<f64, f64>) -> Func<f64, f64> {
     })
 }
 
+/// Zips any number of functions, such that it cycles between them.
+///
+/// Unit interval `floor(t)` selects function `floor(t) mod N`,
+/// which gets called with a parameter that continues where its previous interval ended.
+/// Panics if `fs` is empty.
+pub fn zip_n(fs: Vec<Func<f64, f64>>) -> Func<f64, f64> {
+    assert!(!fs.is_empty(), "zip_n requires at least one function");
+    let n = fs.len() as f64;
+    Arc::new(move |t| {
+        let k = t.floor();
+        let i = k.rem_euclid(n);
+        fs[i as usize](t - k + (k - i) / n)
+    })
+}
+
 /// Returns the `y` component for `x` on a half circle.
 pub fn half_circle() -> Func<f64, f64> {
     Arc::new(move |x| (1.0 - x * x).sqrt())
@@ -74,11 +147,12 @@ where F: 'static + Fn(T) -> U + Send + Sync, U: 'static, V: 'static {
 }
 
 /// Creates a linear combination of two shapes.
-pub fn line<T: Clone, U: Clone, V: Clone>(a: &T, b: &U, t: &V) ->
+pub fn line<T, U, V>(a: &T, b: &U, t: &V) ->
 <T as Add<<<U as Sub<T>>::Output as Mul<V>>::Output>>::Output
-    where U: Sub<T>,
+    where U: Clone + Sub<T>,
+          V: Clone,
           <U as Sub<T>>::Output: Mul<V>,
-          T: Add<<<U as Sub<T>>::Output as Mul<V>>::Output>
+          T: Clone + Add<<<U as Sub<T>>::Output as Mul<V>>::Output>
 {
     let a1 = a.clone();
     let a2 = a.clone();
@@ -87,6 +161,57 @@ pub fn line<T: Clone, U: Clone, V: Clone>(a: &T, b: &U, t: &V) ->
     a1 + (b - a2) * t
 }
 
+/// Creates a surface by sweeping a profile curve along a direction.
+///
+/// The first parameter moves along the profile and the second along `dir` up to `length`.
+pub fn extrude(profile: &PointFunc<f64>, dir: Point, length: f64) -> PointFunc<[f64; 2]> {
+    let sweep = (dir * length) * id::<f64>();
+    (profile.clone().lift_right::<f64>() + sweep.lift_left::<f64>()).into()
+}
+
+/// Creates a surface by linearly blending between two curves.
+///
+/// The first parameter moves along the curves and the second blends from `a` to `b`.
+pub fn loft(a: &PointFunc<f64>, b: &PointFunc<f64>) -> PointFunc<[f64; 2]> {
+    let a = a.clone().lift_right::<f64>();
+    let b = b.clone().lift_right::<f64>();
+    let t = lift_left::<f64, f64, f64>(id());
+    line(&a, &b, &t).into()
+}
+
+/// Creates a weighted sum of three shapes, e.g. for interpolating over a triangle.
+///
+/// The weights are not normalized.
+pub fn barycentric<T: 'static + Copy>(
+    a: &PointFunc<T>,
+    b: &PointFunc<T>,
+    c: &PointFunc<T>,
+    w: [f64; 3]
+) -> PointFunc<T> {
+    a.clone() * w[0] + b.clone() * w[1] + c.clone() * w[2]
+}
+
+/// Creates a cubic Hermite curve.
+///
+/// The curve starts at `p0` with velocity `m0` and ends at `p1` with velocity `m1`.
+pub fn hermite(p0: Point, m0: Point, p1: Point, m1: Point) -> PointFunc<f64> {
+    fn coord(p0: f64, m0: f64, p1: f64, m1: f64) -> Func<f64, f64> {
+        Arc::new(move |t| {
+            let t2 = t * t;
+            let t3 = t2 * t;
+            (2.0 * t3 - 3.0 * t2 + 1.0) * p0 +
+            (t3 - 2.0 * t2 + t) * m0 +
+            (-2.0 * t3 + 3.0 * t2) * p1 +
+            (t3 - t2) * m1
+        })
+    }
+    Point {
+        x: coord(p0.x, m0.x, p1.x, m1.x),
+        y: coord(p0.y, m0.y, p1.y, m1.y),
+        z: coord(p0.z, m0.z, p1.z, m1.z),
+    }
+}
+
 /// Constructs a cubic bezier.
 #[macro_export]
 macro_rules! qbez(
@@ -104,7 +229,7 @@ macro_rules! cbez(
 );
 
 /// Mathematical constant for 360 degrees in radians.
-pub const TAU: f64 = 6.283185307179586;
+pub const TAU: f64 = std::f64::consts::TAU;
 
 #[cfg(test)]
 mod tests {
@@ -126,4 +251,168 @@ mod tests {
         assert_eq!(c(4.0), a(2.0));
         assert_eq!(c(4.5), a(2.5));
     }
+
+    #[test]
+    fn test_zip_n() {
+        let a: Func<f64, f64> = Arc::new(move |t| t);
+        let b: Func<f64, f64> = Arc::new(move |t| -t);
+        let c: Func<f64, f64> = Arc::new(move |t| 10.0 + t);
+        let d = zip_n(vec![a.clone(), b.clone(), c.clone()]);
+        assert_eq!(d(0.0), a(0.0));
+        assert_eq!(d(0.5), a(0.5));
+        assert_eq!(d(1.0), b(0.0));
+        assert_eq!(d(1.5), b(0.5));
+        assert_eq!(d(2.0), c(0.0));
+        assert_eq!(d(2.5), c(0.5));
+        assert_eq!(d(3.0), a(1.0));
+        assert_eq!(d(4.5), b(1.5));
+        assert_eq!(d(5.5), c(1.5));
+        assert_eq!(d(6.0), a(2.0));
+
+        let e = zip_n(vec![a.clone(), b.clone()]);
+        let f = zip(a, b);
+        for i in 0..20 {
+            let t = i as f64 * 0.25;
+            assert_eq!(e(t), f(t));
+        }
+    }
+
+    #[test]
+    fn test_smoothstep() {
+        let f = smoothstep(1.0, 3.0);
+        assert_eq!(f(0.0), 0.0);
+        assert_eq!(f(1.0), 0.0);
+        assert_eq!(f(2.0), 0.5);
+        assert_eq!(f(3.0), 1.0);
+        assert_eq!(f(4.0), 1.0);
+        assert!(f(1.5) < 0.25);
+        assert!(f(2.5) > 0.75);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_smoothstep_equal_edges() {
+        let _ = smoothstep(1.0, 1.0);
+    }
+
+    #[test]
+    fn test_sawtooth() {
+        let f = sawtooth(2.0);
+        assert_eq!(f(0.0), 0.0);
+        assert_eq!(f(0.5), 0.25);
+        assert_eq!(f(1.0), 0.5);
+        assert_eq!(f(1.5), 0.75);
+        assert_eq!(f(2.0), 0.0);
+        assert_eq!(f(-0.5), 0.75);
+    }
+
+    #[test]
+    fn test_triangle_wave() {
+        let f = triangle_wave(2.0);
+        assert_eq!(f(0.0), 0.0);
+        assert_eq!(f(0.5), 0.5);
+        assert_eq!(f(1.0), 1.0);
+        assert_eq!(f(1.5), 0.5);
+        assert_eq!(f(2.0), 0.0);
+        assert_eq!(f(-0.5), 0.5);
+    }
+
+    #[test]
+    fn test_smootherstep() {
+        let f = smootherstep(1.0, 3.0);
+        assert_eq!(f(0.0), 0.0);
+        assert_eq!(f(1.0), 0.0);
+        assert_eq!(f(2.0), 0.5);
+        assert_eq!(f(3.0), 1.0);
+        assert_eq!(f(4.0), 1.0);
+        assert!(f(1.5) < smoothstep(1.0, 3.0)(1.5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_smootherstep_equal_edges() {
+        let _ = smootherstep(2.0, 2.0);
+    }
+
+    #[test]
+    fn test_clamp() {
+        let f = clamp(-1.0, 2.0);
+        assert_eq!(f(-3.0), -1.0);
+        assert_eq!(f(0.5), 0.5);
+        assert_eq!(f(5.0), 2.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_clamp_inverted() {
+        let _ = clamp(2.0, -1.0);
+    }
+
+    #[test]
+    fn test_gaussian() {
+        let f = gaussian(1.0, 0.5);
+        assert_eq!(f(1.0), 1.0);
+        assert_eq!(f(0.5), f(1.5));
+        assert_eq!(f(0.0), f(2.0));
+        assert!(f(0.5) < 1.0);
+        assert!(f(0.0) < f(0.5));
+        assert!((f(1.5) - (-0.5f64).exp()).abs() < 0.0000001);
+    }
+
+    #[test]
+    fn test_hermite() {
+        let p0: Point = [0.0, 0.0, 0.0].into();
+        let m0: Point = [1.0, 0.0, 0.0].into();
+        let p1: Point = [1.0, 1.0, 0.0].into();
+        let m1: Point = [0.0, 2.0, 1.0].into();
+        let a = hermite(p0, m0, p1, m1);
+        assert_eq!(a.call(0.0), p0);
+        assert_eq!(a.call(1.0), p1);
+
+        let eps = 0.0000001;
+        let da = a.diff(eps);
+        assert!(da.call(0.0).distance(m0) < 0.000001);
+        assert!(da.call(1.0 - eps).distance(m1) < 0.000001);
+    }
+
+    #[test]
+    fn test_barycentric() {
+        let a = Point::circle();
+        let b = Point::x();
+        let c: PointFunc<f64> = [0.0, 0.0, 1.0].into();
+        let d = barycentric(&a, &b, &c, [1.0, 0.0, 0.0]);
+        let e = barycentric(&a, &b, &c, [0.5, 0.5, 2.0]);
+        for &t in &[0.0, 0.25, 0.7] {
+            assert_eq!(d.call(t), a.call(t));
+            let p = a.call(t) * 0.5 + b.call(t) * 0.5 + c.call(t) * 2.0;
+            assert_eq!(e.call(t), p);
+        }
+    }
+
+    #[test]
+    fn test_extrude() {
+        let a = extrude(&Point::circle(), Point::new(0.0, 0.0, 1.0), 2.0);
+        let r1 = a.call([0.0, 0.0]);
+        assert_eq!(r1, Point::new(1.0, 0.0, 0.0));
+        let r2 = a.call([0.0, 0.5]);
+        assert_eq!(r2, Point::new(1.0, 0.0, 1.0));
+        let r3 = a.call([0.25, 1.0]);
+        assert!(r3.approx_eq(&Point::new(0.0, 1.0, 2.0), 1e-12));
+    }
+
+    #[test]
+    fn test_loft() {
+        let a = Point::circle();
+        let b = Point::circle() * 2.0 + [0.0, 0.0, 1.0];
+        let c = loft(&a, &b);
+        for i in 0..=4 {
+            for j in 0..8 {
+                let v = i as f64 / 4.0;
+                let u = j as f64 / 8.0;
+                let p = c.call([u, v]);
+                assert!((p.x.hypot(p.y) - (1.0 + v)).abs() < 1e-12);
+                assert!((p.z - v).abs() < 1e-12);
+            }
+        }
+    }
 }
@@ -0,0 +1,280 @@
+//! Mesh utilities for sampled surfaces.
+
+use super::*;
+
+/// Exports a sampled surface as a Wavefront OBJ string.
+///
+/// Samples a grid of `n[0]` by `n[1]` vertices and connects them with quad faces.
+pub fn export_obj(surface: &PointFunc<[f64; 2]>, n: [usize; 2]) -> String {
+    export_obj_wrapped(surface, n, [false, false])
+}
+
+/// Exports a sampled surface as a Wavefront OBJ string, with seam handling.
+///
+/// When `wrap[0]` or `wrap[1]` is set, the surface is assumed to be periodic
+/// in that parameter. The seam is then sampled only once
+/// and faces connect the last row or column back to the first.
+/// Wrapping is skipped for a parameter with fewer than 3 samples,
+/// since the seam faces would be degenerate.
+pub fn export_obj_wrapped(
+    surface: &PointFunc<[f64; 2]>,
+    n: [usize; 2],
+    wrap: [bool; 2]
+) -> String {
+    use std::fmt::Write;
+
+    let wrap = [wrap[0] && n[0] >= 3, wrap[1] && n[1] >= 3];
+    let scale = |k: usize, w: bool| if w && k > 0 {(k - 1) as f64 / k as f64} else {1.0};
+    let su = scale(n[0], wrap[0]);
+    let sv = scale(n[1], wrap[1]);
+    let grid = surface.clone().map(move |p: [f64; 2]| [p[0] * su, p[1] * sv]).sample_grid(n);
+
+    let mut s = String::new();
+    for row in &grid {
+        for p in row {
+            writeln!(s, "v {} {} {}", p.x, p.y, p.z).unwrap();
+        }
+    }
+    let rows = if wrap[0] {n[0]} else {n[0].saturating_sub(1)};
+    let cols = if wrap[1] {n[1]} else {n[1].saturating_sub(1)};
+    let index = |i: usize, j: usize| (i % n[0]) * n[1] + j % n[1] + 1;
+    for i in 0..rows {
+        for j in 0..cols {
+            writeln!(s, "f {} {} {} {}",
+                index(i, j),
+                index(i + 1, j),
+                index(i + 1, j + 1),
+                index(i, j + 1)
+            ).unwrap();
+        }
+    }
+    s
+}
+
+/// Returns triangle indices for a grid of `n[0]` rows and `n[1]` columns of vertices.
+///
+/// Vertices are indexed row by row, like the output of `sample_grid` flattened.
+/// Each grid cell is split into two triangles, with the same winding as `export_obj`.
+pub fn triangulate(n: [usize; 2]) -> Vec<[u32; 3]> {
+    let rows = n[0].saturating_sub(1);
+    let cols = n[1].saturating_sub(1);
+    let index = |i: usize, j: usize| (i * n[1] + j) as u32;
+    let mut tris = Vec::with_capacity(2 * rows * cols);
+    for i in 0..rows {
+        for j in 0..cols {
+            let a = index(i, j);
+            let b = index(i + 1, j);
+            let c = index(i + 1, j + 1);
+            let d = index(i, j + 1);
+            tris.push([a, b, c]);
+            tris.push([a, c, d]);
+        }
+    }
+    tris
+}
+
+/// Returns the signed volume enclosed by a sampled closed surface.
+///
+/// Samples a grid of `n[0]` by `n[1]` vertices, triangulates it and sums
+/// the signed volumes of tetrahedra between the origin and each triangle.
+/// The sign depends on the orientation of the surface.
+pub fn signed_volume(surface: &PointFunc<[f64; 2]>, n: [usize; 2]) -> f64 {
+    let points: Vec<Point> = surface.sample_grid(n).into_iter().flatten().collect();
+    triangulate(n).iter().map(|&[a, b, c]| {
+        let (a, b, c) = (points[a as usize], points[b as usize], points[c as usize]);
+        a.dot(b.cross(c))
+    }).sum::<f64>() / 6.0
+}
+
+/// Returns the area of a sampled surface.
+///
+/// Samples a grid of `n[0]` by `n[1]` vertices, triangulates it and sums the triangle areas.
+pub fn surface_area(surface: &PointFunc<[f64; 2]>, n: [usize; 2]) -> f64 {
+    let points: Vec<Point> = surface.sample_grid(n).into_iter().flatten().collect();
+    triangulate(n).iter().map(|&[a, b, c]| {
+        let (a, b, c) = (points[a as usize], points[b as usize], points[c as usize]);
+        (b - a).cross(c - a).norm()
+    }).sum::<f64>() / 2.0
+}
+
+/// Returns the signed distance from a point to a sampled surface.
+///
+/// Samples a grid of `n[0]` by `n[1]` vertices, triangulates it and finds the nearest triangle.
+/// The distance is positive on the side of the triangle normal, which follows the winding
+/// of `triangulate`, and negative on the other side.
+/// For surfaces that are not closed, the sign may be wrong.
+pub fn signed_distance_to_surface(surface: &PointFunc<[f64; 2]>, q: Point, n: [usize; 2]) -> f64 {
+    let points: Vec<Point> = surface.sample_grid(n).into_iter().flatten().collect();
+    let mut best = (f64::INFINITY, 0.0);
+    for &[a, b, c] in &triangulate(n) {
+        let (a, b, c) = (points[a as usize], points[b as usize], points[c as usize]);
+        let normal = (b - a).cross(c - a);
+        // Skip degenerate triangles, e.g. at the poles of a sphere.
+        if normal.norm() < 1e-12 {continue};
+        let p = closest_point_on_triangle(q, a, b, c);
+        let dist = q.distance(p);
+        if dist < best.0 {
+            best = (dist, (q - p).dot(normal));
+        }
+    }
+    if best.1 < 0.0 {-best.0} else {best.0}
+}
+
+/// Returns the mean curvature at each vertex of a sampled surface grid.
+///
+/// Uses central differences of the grid as partial derivatives, so the grid
+/// should be sampled uniformly in each parameter, like `sample_grid`.
+/// The curvature is positive where the surface bends away from the normal `du × dv`,
+/// e.g. `1 / r` for a sphere of radius `r` with outward normals.
+/// Boundary vertices and degenerate vertices, e.g. at the poles of a sphere, get zero.
+pub fn mean_curvature_grid(grid: &[Vec<Point>]) -> Vec<Vec<f64>> {
+    let rows = grid.len();
+    grid.iter().enumerate().map(|(i, row)| {
+        let cols = row.len();
+        (0..cols).map(|j| {
+            if i == 0 || j == 0 || i + 1 >= rows || j + 1 >= cols {return 0.0};
+            let p = grid[i][j];
+            let du = (grid[i + 1][j] - grid[i - 1][j]) * 0.5;
+            let dv = (grid[i][j + 1] - grid[i][j - 1]) * 0.5;
+            let duu = grid[i + 1][j] - p * 2.0 + grid[i - 1][j];
+            let dvv = grid[i][j + 1] - p * 2.0 + grid[i][j - 1];
+            let duv = (grid[i + 1][j + 1] - grid[i + 1][j - 1] -
+                       grid[i - 1][j + 1] + grid[i - 1][j - 1]) * 0.25;
+            let n = du.cross(dv);
+            // First and second fundamental forms.
+            let (e, f, g) = (du.dot(du), du.dot(dv), dv.dot(dv));
+            let det = e * g - f * f;
+            if n.norm() < 1e-12 || det < 1e-24 {return 0.0};
+            let n = n.normalize();
+            let (l, m, nn) = (duu.dot(n), duv.dot(n), dvv.dot(n));
+            -(e * nn - 2.0 * f * m + g * l) / (2.0 * det)
+        }).collect()
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sphere() -> PointFunc<[f64; 2]> {
+        let f = Arc::new(move |p: [f64; 2]| from_spherical(1.0, p[0] * 0.5 * TAU, p[1] * TAU));
+        let fx = f.clone();
+        let fy = f.clone();
+        let fz = f;
+        Point {
+            x: Arc::new(move |p| fx(p).x),
+            y: Arc::new(move |p| fy(p).y),
+            z: Arc::new(move |p| fz(p).z),
+        }
+    }
+
+    #[test]
+    fn test_signed_distance_to_surface() {
+        let a = sphere();
+        let n = [40, 80];
+        assert!((signed_distance_to_surface(&a, Point::origin(), n) + 1.0).abs() < 0.01);
+        let inside = Point::new(0.3, 0.2, -0.1);
+        let d = signed_distance_to_surface(&a, inside, n);
+        assert!(d < 0.0);
+        assert!((d + 1.0 - inside.norm()).abs() < 0.01);
+        let outside = Point::new(0.0, 2.0, 1.0);
+        let d = signed_distance_to_surface(&a, outside, n);
+        assert!((d - (outside.norm() - 1.0)).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_mean_curvature_grid() {
+        let plane = Point::ground_plane().sample_grid([10, 10]);
+        let h = mean_curvature_grid(&plane);
+        assert!(h.iter().flatten().all(|&h| h.abs() < 1e-12));
+
+        let sphere = sphere() * 2.0;
+        let h = mean_curvature_grid(&sphere.sample_grid([41, 81]));
+        assert_eq!(h.len(), 41);
+        assert_eq!(h[0][40], 0.0);
+        for row in &h[5..36] {
+            for &h in &row[1..80] {
+                assert!((h - 0.5).abs() < 0.01);
+            }
+        }
+    }
+
+    fn check_obj(s: &str, n: [usize; 2], faces: usize) {
+        let vertices = s.lines().filter(|l| l.starts_with("v ")).count();
+        assert_eq!(vertices, n[0] * n[1]);
+        let mut face_count = 0;
+        for l in s.lines().filter(|l| l.starts_with("f ")) {
+            face_count += 1;
+            for ind in l.split_whitespace().skip(1) {
+                let ind: usize = ind.parse().unwrap();
+                assert!(ind >= 1 && ind <= vertices);
+            }
+        }
+        assert_eq!(face_count, faces);
+    }
+
+    #[test]
+    fn obj() {
+        let a = Point::ground_plane();
+        let s = export_obj(&a, [3, 4]);
+        check_obj(&s, [3, 4], 6);
+        assert!(s.starts_with("v 0 0 0\n"));
+    }
+
+    #[test]
+    fn obj_wrapped() {
+        let a = Point::circle().lift_right::<f64>() + Point::z().lift_left::<f64>();
+        let a: PointFunc<[f64; 2]> = a.into();
+        let s = export_obj_wrapped(&a, [8, 2], [true, false]);
+        check_obj(&s, [8, 2], 8);
+    }
+
+    #[test]
+    fn obj_wrapped_too_few_samples() {
+        let a = sphere();
+        for &n in &[[2, 2], [1, 2], [2, 5]] {
+            let s = export_obj_wrapped(&a, n, [true, true]);
+            for l in s.lines().filter(|l| l.starts_with("f ")) {
+                let mut inds: Vec<&str> = l.split_whitespace().skip(1).collect();
+                inds.sort();
+                inds.dedup();
+                assert_eq!(inds.len(), 4);
+            }
+        }
+        assert_eq!(export_obj_wrapped(&a, [2, 2], [true, true]), export_obj(&a, [2, 2]));
+        let s = export_obj_wrapped(&a, [2, 5], [true, true]);
+        check_obj(&s, [2, 5], 5);
+    }
+
+    #[test]
+    fn test_triangulate() {
+        let n = [4, 3];
+        let tris = triangulate(n);
+        assert_eq!(tris.len(), 2 * 3 * 2);
+        for tri in &tris {
+            for &ind in tri {
+                assert!((ind as usize) < n[0] * n[1]);
+            }
+        }
+        assert_eq!(tris[0], [0, 3, 4]);
+        assert_eq!(tris[1], [0, 4, 1]);
+        assert!(triangulate([1, 5]).is_empty());
+    }
+
+    #[test]
+    fn test_signed_volume() {
+        let v = signed_volume(&sphere(), [64, 64]);
+        let exact = 2.0 / 3.0 * TAU;
+        assert!((v - exact).abs() < 0.01 * exact);
+        let v = signed_volume(&sphere().map(|p: [f64; 2]| [p[1], p[0]]), [64, 64]);
+        assert!((v + exact).abs() < 0.01 * exact);
+    }
+
+    #[test]
+    fn test_surface_area() {
+        let a = surface_area(&sphere(), [64, 64]);
+        let exact = 2.0 * TAU;
+        assert!((a - exact).abs() < 0.01 * exact);
+        assert!((surface_area(&Point::ground_plane(), [5, 7]) - 1.0).abs() < 1e-12);
+    }
+}
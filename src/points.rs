@@ -0,0 +1,435 @@
+//! Functions on sampled points.
+
+use super::*;
+
+/// Returns the total length of the polyline through the points.
+pub fn polyline_length(points: &[Point]) -> f64 {
+    points.windows(2).map(|w| w[0].distance(w[1])).sum()
+}
+
+/// Returns the index, point and distance of the sampled point nearest to `query`.
+///
+/// Returns `None` if there are no points.
+pub fn closest_point(points: &[Point], query: Point) -> Option<(usize, Point, f64)> {
+    points.iter()
+        .enumerate()
+        .map(|(i, &p)| (i, p, p.distance(query)))
+        .min_by(|a, b| a.2.partial_cmp(&b.2).unwrap_or(std::cmp::Ordering::Equal))
+}
+
+/// Returns the average of the points.
+///
+/// Returns the origin if there are no points.
+pub fn centroid(points: &[Point]) -> Point {
+    if points.is_empty() {return [0.0; 3].into()};
+    let sum = points.iter().fold([0.0; 3].into(), |a: Point, &b| a + b);
+    sum * (1.0 / points.len() as f64)
+}
+
+/// Returns a center and radius of a sphere containing all the points.
+///
+/// Uses the centroid as center, so the sphere is not necessarily minimal.
+pub fn bounding_sphere(points: &[Point]) -> (Point, f64) {
+    let center = centroid(points);
+    let radius = points.iter().map(|p| p.distance(center)).fold(0.0, f64::max);
+    (center, radius)
+}
+
+/// Returns the point minimizing the sum of distances to the points.
+///
+/// Starts at the centroid and runs `iters` iterations of Weiszfeld's algorithm.
+/// Points coinciding with the current estimate are skipped.
+/// Returns the origin if there are no points.
+pub fn geometric_median(points: &[Point], iters: usize) -> Point {
+    let mut m = centroid(points);
+    for _ in 0..iters {
+        let mut sum: Point = [0.0; 3].into();
+        let mut weight = 0.0;
+        for &p in points {
+            let d = p.distance(m);
+            if d < 1e-12 {continue};
+            sum += p * (1.0 / d);
+            weight += 1.0 / d;
+        }
+        if weight == 0.0 {break};
+        m = sum * (1.0 / weight);
+    }
+    m
+}
+
+/// Removes consecutive points closer than `eps` to the previously kept point.
+pub fn dedup_points(points: &[Point], eps: f64) -> Vec<Point> {
+    let mut res: Vec<Point> = Vec::with_capacity(points.len());
+    for &p in points {
+        match res.last() {
+            Some(&q) if q.distance(p) < eps => {}
+            _ => res.push(p),
+        }
+    }
+    res
+}
+
+/// Returns the convex hull of the points projected onto the xy-plane.
+///
+/// Uses the monotone chain algorithm.
+/// The hull is in counter-clockwise order, starting at the point with smallest x,
+/// and excludes points on the edges of the hull.
+pub fn convex_hull_xy(points: &[Point]) -> Vec<Point> {
+    let mut ps = points.to_vec();
+    ps.sort_by(|a, b| (a.x, a.y).partial_cmp(&(b.x, b.y)).unwrap_or(std::cmp::Ordering::Equal));
+    ps.dedup_by(|a, b| a.x == b.x && a.y == b.y);
+    if ps.len() < 3 {return ps};
+
+    let turn = |o: Point, a: Point, b: Point| (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x);
+    let mut hull: Vec<Point> = Vec::with_capacity(2 * ps.len());
+    // Lower hull.
+    for &p in &ps {
+        while hull.len() >= 2 && turn(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    // Upper hull.
+    let lower = hull.len() + 1;
+    for &p in ps.iter().rev().skip(1) {
+        while hull.len() >= lower && turn(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    hull.pop();
+    hull
+}
+
+/// Returns the winding number of a closed loop about a point, projected onto the xy-plane.
+///
+/// Sums the signed angle increments between consecutive points,
+/// including the segment from the last point back to the first.
+/// The result is close to an integer, positive for counter-clockwise loops.
+pub fn winding_number_xy(points: &[Point], about: Point) -> f64 {
+    let n = points.len();
+    let mut sum = 0.0;
+    for i in 0..n {
+        let a = points[i] - about;
+        let b = points[(i + 1) % n] - about;
+        sum += (a.x * b.y - a.y * b.x).atan2(a.x * b.x + a.y * b.y);
+    }
+    sum / TAU
+}
+
+/// Returns `true` if a point is inside a closed polygon, projected onto the xy-plane.
+///
+/// Uses the even-odd rule by casting a ray along positive x.
+/// Edges are half-open in y, such that a ray through a vertex is counted once.
+/// Points within `1e-9` times the edge length from an edge count as inside.
+pub fn point_in_polygon_xy(polygon: &[Point], q: Point) -> bool {
+    let n = polygon.len();
+    let mut inside = false;
+    for i in 0..n {
+        let a = polygon[i];
+        let b = polygon[(i + 1) % n];
+        let (ex, ey) = (b.x - a.x, b.y - a.y);
+        let (qx, qy) = (q.x - a.x, q.y - a.y);
+        let len2 = ex * ex + ey * ey;
+        // Cross and dot products scale with the edge length, so compare against its square.
+        let tol = 1e-9 * len2;
+        let cross = ex * qy - ey * qx;
+        let dot = ex * qx + ey * qy;
+        if len2 > 0.0 && cross.abs() <= tol && dot >= -tol && dot <= len2 + tol {
+            return true;
+        }
+        if (a.y > q.y) != (b.y > q.y) {
+            let x = a.x + (q.y - a.y) * (b.x - a.x) / (b.y - a.y);
+            if q.x < x {inside = !inside};
+        }
+    }
+    inside
+}
+
+/// Replaces each corner of a closed polygon with a circular arc of the given radius.
+///
+/// The radius is clamped per corner, such that an arc uses at most half of each adjacent edge.
+/// Arcs are sampled with 16 segments per quarter turn.
+/// Corners where the edges are parallel or have zero length are kept as they are.
+pub fn round_corners(points: &[Point], radius: f64) -> Vec<Point> {
+    let n = points.len();
+    if n < 3 {return points.to_vec()};
+    let mut res = vec![];
+    for i in 0..n {
+        let p = points[i];
+        let a = points[(i + n - 1) % n];
+        let b = points[(i + 1) % n];
+        let (la, lb) = (p.distance(a), p.distance(b));
+        let u = (a - p).normalize();
+        let v = (b - p).normalize();
+        // Interior angle of the corner.
+        let theta = u.angle_between(v);
+        let turn = 0.5 * TAU - theta;
+        if la == 0.0 || lb == 0.0 || !turn.is_finite() || turn < 1e-9 || theta < 1e-9 {
+            res.push(p);
+            continue;
+        }
+        let half = 0.5 * theta;
+        let d = (radius / half.tan()).min(0.5 * la).min(0.5 * lb);
+        let r = d * half.tan();
+        let center = p + (u + v).normalize() * (r / half.sin());
+        let start = p + u * d;
+        let end = p + v * d;
+        let k = (turn / (TAU / 64.0)).ceil().max(1.0) as usize;
+        let arc = slerp(start - center, end - center);
+        for j in 0..=k {
+            res.push(center + arc.call(j as f64 / k as f64) * r);
+        }
+    }
+    res
+}
+
+impl AABB for [Point] {
+    type Corner = Point;
+    /// Returns infinite inverted corners if there are no points.
+    fn aabb(&self) -> (Point, Point) {
+        let inf = f64::INFINITY;
+        self.iter().fold(
+            ([inf; 3].into(), [-inf; 3].into()),
+            |(min, max): (Point, Point), &p| (min.min(p), max.max(p))
+        )
+    }
+}
+
+/// A collection of sampled points.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct PointCloud(pub Vec<Point>);
+
+impl PointCloud {
+    /// Returns the number of points.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there are no points.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the average of the points.
+    pub fn centroid(&self) -> Point {
+        centroid(&self.0)
+    }
+}
+
+impl AABB for PointCloud {
+    type Corner = Point;
+    fn aabb(&self) -> (Point, Point) {
+        self.0.aabb()
+    }
+}
+
+impl std::iter::FromIterator<Point> for PointCloud {
+    fn from_iter<I: IntoIterator<Item = Point>>(iter: I) -> Self {
+        PointCloud(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_winding_number_xy() {
+        let points: Vec<Point> = Point::circle().iter_samples(100).collect();
+        assert!((winding_number_xy(&points, Point::origin()) - 1.0).abs() < 1e-9);
+        let reversed: Vec<Point> = points.iter().rev().cloned().collect();
+        assert!((winding_number_xy(&reversed, Point::origin()) + 1.0).abs() < 1e-9);
+        assert!(winding_number_xy(&points, Point::new(2.0, 0.0, 0.0)).abs() < 1e-9);
+        assert_eq!(winding_number_xy(&[], Point::origin()), 0.0);
+    }
+
+    #[test]
+    fn test_geometric_median() {
+        let mut points: Vec<Point> = Point::circle().iter_samples(100).collect();
+        points.push(Point::new(100.0, 0.0, 0.0));
+        let m = geometric_median(&points, 100);
+        assert!(m.distance(Point::origin()) < 0.1);
+        assert!(centroid(&points).distance(Point::origin()) > 0.5);
+        let p = Point::new(1.0, 2.0, 3.0);
+        assert_eq!(geometric_median(&[p], 10), p);
+        assert_eq!(geometric_median(&[], 10), Point::origin());
+    }
+
+    #[test]
+    fn test_point_in_polygon_xy() {
+        let square = [
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(1.0, 1.0, 0.0),
+            Point::new(0.0, 1.0, 0.0),
+        ];
+        assert!(point_in_polygon_xy(&square, Point::new(0.5, 0.5, 3.0)));
+        assert!(!point_in_polygon_xy(&square, Point::new(1.5, 0.5, 0.0)));
+        assert!(!point_in_polygon_xy(&square, Point::new(-0.5, 0.5, 0.0)));
+        // Rays through vertices.
+        assert!(!point_in_polygon_xy(&square, Point::new(-1.0, 1.0, 0.0)));
+        assert!(!point_in_polygon_xy(&square, Point::new(-1.0, 0.0, 0.0)));
+        // On edges and corners.
+        assert!(point_in_polygon_xy(&square, Point::new(0.5, 0.0, 0.0)));
+        assert!(point_in_polygon_xy(&square, Point::new(1.0, 0.3, 0.0)));
+        assert!(point_in_polygon_xy(&square, Point::new(1.0, 1.0, 0.0)));
+
+        let diamond = [
+            Point::new(0.0, -1.0, 0.0),
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(0.0, 1.0, 0.0),
+            Point::new(-1.0, 0.0, 0.0),
+        ];
+        assert!(point_in_polygon_xy(&diamond, Point::new(0.5, 0.0, 0.0)));
+        assert!(!point_in_polygon_xy(&diamond, Point::new(-2.0, 0.0, 0.0)));
+        assert!(!point_in_polygon_xy(&[], Point::origin()));
+
+        // Points on slanted edges, which are rarely exactly on the edge after rounding.
+        let triangle = [
+            Point::new(0.1, 0.2, 0.0),
+            Point::new(3.3, 0.7, 0.0),
+            Point::new(0.9, 2.9, 0.0),
+        ];
+        for i in 0..3 {
+            let (a, b) = (triangle[i], triangle[(i + 1) % 3]);
+            for j in 1..100 {
+                let q = a + (b - a) * (j as f64 / 100.0);
+                assert!(point_in_polygon_xy(&triangle, q));
+            }
+        }
+        assert!(!point_in_polygon_xy(&triangle, Point::new(2.0, 0.2, 0.0)));
+    }
+
+    #[test]
+    fn test_round_corners() {
+        let square = [
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(1.0, 1.0, 0.0),
+            Point::new(0.0, 1.0, 0.0),
+        ];
+        let res = round_corners(&square, 0.2);
+        // A quarter turn per corner.
+        assert_eq!(res.len(), 4 * 17);
+        for c in &square {
+            assert!(res.iter().all(|p| p.distance(*c) > 0.05));
+        }
+        let center = Point::new(0.2, 0.2, 0.0);
+        for p in &res[..17] {
+            assert!((p.distance(center) - 0.2).abs() < 1e-12);
+        }
+        // Edges are shortened by the radius at each end.
+        assert!(res[16].approx_eq(&Point::new(0.2, 0.0, 0.0), 1e-12));
+        assert!(res[17].approx_eq(&Point::new(0.8, 0.0, 0.0), 1e-12));
+        assert!((polyline_length(&res[16..18]) - 0.6).abs() < 1e-12);
+
+        // Radius is clamped to half the edges.
+        let res = round_corners(&square, 10.0);
+        let center = Point::new(0.5, 0.5, 0.0);
+        assert!(res.iter().all(|p| (p.distance(center) - 0.5).abs() < 1e-12));
+    }
+
+    #[test]
+    fn test_polyline_length() {
+        let a = Point::circle();
+        let n = 10000;
+        let points: Vec<Point> = (0..=n).map(|i| a.call(i as f64 / n as f64)).collect();
+        assert!((polyline_length(&points) - TAU).abs() < 0.000001);
+        assert_eq!(polyline_length(&points[..1]), 0.0);
+        assert_eq!(polyline_length(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_closest_point() {
+        let points: Vec<Point> = vec![
+            [0.0, 0.0, 0.0].into(),
+            [1.0, 0.0, 0.0].into(),
+            [0.0, 2.0, 0.0].into(),
+            [0.0, 0.0, -3.0].into(),
+        ];
+        let (i, p, d) = closest_point(&points, [0.0, 1.5, 0.0].into()).unwrap();
+        assert_eq!(i, 2);
+        assert_eq!(p, points[2]);
+        assert_eq!(d, 0.5);
+        assert!(closest_point(&[], [0.0; 3].into()).is_none());
+    }
+
+    #[test]
+    fn test_bounding_sphere() {
+        let points: Vec<Point> = vec![
+            [1.0, 0.0, 0.0].into(),
+            [-1.0, 0.0, 0.0].into(),
+            [0.0, 2.0, 0.0].into(),
+            [0.0, -2.0, 0.0].into(),
+        ];
+        let (center, radius) = bounding_sphere(&points);
+        assert_eq!(center, [0.0; 3].into());
+        assert_eq!(radius, 2.0);
+        for p in &points {
+            assert!(p.distance(center) <= radius);
+        }
+        assert_eq!(bounding_sphere(&[]), ([0.0; 3].into(), 0.0));
+    }
+
+    #[test]
+    fn test_dedup_points() {
+        let a = Point::new(0.0, 0.0, 0.0);
+        let b = Point::new(1.0, 0.0, 0.0);
+        let c = Point::new(1.0, 1.0, 0.0);
+        let points = vec![a, a, b, b + 1e-9, b, c, c, a];
+        assert_eq!(dedup_points(&points, 1e-6), vec![a, b, c, a]);
+        assert!(dedup_points(&[], 1e-6).is_empty());
+    }
+
+    #[test]
+    fn test_convex_hull_xy() {
+        let a = Point::circle();
+        let n = 16;
+        let mut points: Vec<Point> = (0..n).map(|i| a.call(i as f64 / n as f64)).collect();
+        points.push(Point::origin());
+        points.push(Point::new(0.5, -0.25, 3.0));
+        let hull = convex_hull_xy(&points);
+        assert_eq!(hull.len(), n);
+        assert!(hull.iter().all(|p| (p.norm() - 1.0).abs() < 1e-12));
+        assert_eq!(hull[0], a.call(0.5));
+        for i in 0..n {
+            let p = hull[i];
+            let q = hull[(i + 1) % n];
+            let ang = q.y.atan2(q.x) - p.y.atan2(p.x);
+            assert!((ang.rem_euclid(TAU) - TAU / n as f64).abs() < 1e-9);
+        }
+
+        let square = [
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(0.5, 0.0, 0.0),
+            Point::new(1.0, 1.0, 0.0),
+            Point::new(0.0, 1.0, 0.0),
+            Point::new(0.5, 0.5, 0.0),
+        ];
+        assert_eq!(convex_hull_xy(&square), vec![
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(1.0, 1.0, 0.0),
+            Point::new(0.0, 1.0, 0.0),
+        ]);
+    }
+
+    #[test]
+    fn point_cloud() {
+        let a = Point::circle() + [1.0, 0.0, 2.0];
+        let cloud: PointCloud = a.iter_samples(4).collect();
+        assert_eq!(cloud.len(), 4);
+        assert!(!cloud.is_empty());
+        let (min, max) = cloud.aabb();
+        assert!(min.approx_eq(&Point::new(0.0, -1.0, 2.0), 1e-12));
+        assert!(max.approx_eq(&Point::new(2.0, 1.0, 2.0), 1e-12));
+        assert!(cloud.centroid().approx_eq(&Point::new(1.0, 0.0, 2.0), 1e-12));
+
+        let empty: PointCloud = vec![].into_iter().collect();
+        assert!(empty.is_empty());
+        let (min, max) = empty.aabb();
+        assert!(min.x > max.x);
+    }
+}
@@ -0,0 +1,187 @@
+//! A simple 3D point renderer.
+//!
+//! Requires the `renderer` feature.
+
+use super::*;
+
+use graphics::{line, rectangle, Context, Graphics, Transformed};
+use vecmath::Matrix4;
+
+/// The color and radius of a rendered point.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Style {
+    /// Color in RGBA.
+    pub color: [f32; 4],
+    /// Radius in normalized device coordinates.
+    pub radius: f64,
+}
+
+impl Default for Style {
+    /// Returns black points of radius `0.01`.
+    fn default() -> Style {
+        Style {
+            color: [0.0, 0.0, 0.0, 1.0],
+            radius: 0.01,
+        }
+    }
+}
+
+/// Renders sampled points as squares and polylines as line segments.
+#[derive(Clone, Debug, Default)]
+pub struct Renderer {
+    /// Points with their style.
+    pub points: Vec<(Point, Style)>,
+    /// Line segments with their style, where the radius is the line radius.
+    pub segments: Vec<([Point; 2], Style)>,
+}
+
+impl Renderer {
+    /// Creates a new empty renderer.
+    pub fn new() -> Renderer {
+        Renderer {
+            points: vec![],
+            segments: vec![],
+        }
+    }
+
+    /// Samples `n` points of a curve over `[0, 1)` with the default style.
+    pub fn sample(&mut self, p: &PointFunc<f64>, n: usize) {
+        self.sample_styled(p, n, Style::default().color, Style::default().radius);
+    }
+
+    /// Samples `n` points of a curve over `[0, 1)` with a color and radius.
+    pub fn sample_styled(&mut self, p: &PointFunc<f64>, n: usize, color: [f32; 4], radius: f64) {
+        let style = Style {color, radius};
+        self.points.extend(p.iter_samples(n).map(|q| (q, style)));
+    }
+
+    /// Samples a grid of `n[0]` rows and `n[1]` columns over `[0, 1)²` with the default style.
+    pub fn sample2(&mut self, p: &PointFunc<[f64; 2]>, n: [usize; 2]) {
+        let style = Style::default();
+        for i in 0..n[0] {
+            for j in 0..n[1] {
+                let q = p.call([i as f64 / n[0] as f64, j as f64 / n[1] as f64]);
+                self.points.push((q, style));
+            }
+        }
+    }
+
+    /// Samples a curve as a polyline of `n` segments over `[0, 1]`.
+    ///
+    /// Uses the default color with a line radius of `0.005`.
+    pub fn sample_polyline(&mut self, p: &PointFunc<f64>, n: usize) {
+        let style = Style {radius: 0.005, ..Style::default()};
+        let mut prev = p.call(0.0);
+        for i in 1..=n {
+            let q = p.call(i as f64 / n as f64);
+            self.segments.push(([prev, q], style));
+            prev = q;
+        }
+    }
+
+    /// Transforms the points by a model-view-projection matrix.
+    ///
+    /// Points behind the camera are skipped.
+    pub fn project(&self, mvp: &Matrix4<f32>) -> Vec<Projected> {
+        self.points.iter().filter_map(|&(p, style)| {
+            project_point(mvp, p).map(|(pos, depth)| Projected {pos, depth, style})
+        }).collect()
+    }
+
+    /// Draws the segments and points transformed by a model-view-projection matrix.
+    ///
+    /// Segments are drawn first.
+    /// Points are drawn from far to near, such that nearer points overdraw farther ones.
+    pub fn draw(&self, draw_size: [f64; 2], mvp: &Matrix4<f32>, c: &Context, g: &mut impl Graphics) {
+        let halfw = draw_size[0] / 2.0;
+        let tr = c.transform.trans(halfw, draw_size[1] / 2.0).scale(halfw, -halfw);
+        for &([a, b], style) in &self.segments {
+            if let (Some((a, _)), Some((b, _))) = (project_point(mvp, a), project_point(mvp, b)) {
+                line(style.color, style.radius, [a[0], a[1], b[0], b[1]], tr, g);
+            }
+        }
+        let mut points = self.project(mvp);
+        sort_by_depth(&mut points);
+        for p in &points {
+            let rad = p.style.radius;
+            rectangle(
+                p.style.color,
+                [p.pos[0] - rad, p.pos[1] - rad, 2.0 * rad, 2.0 * rad],
+                tr, g
+            );
+        }
+    }
+}
+
+/// A point transformed into normalized device coordinates.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Projected {
+    /// Position on screen.
+    pub pos: [f64; 2],
+    /// Depth, larger is farther away.
+    pub depth: f32,
+    /// Style of the point.
+    pub style: Style,
+}
+
+/// Returns the screen position and depth of a point, or `None` if it is behind the camera.
+fn project_point(mvp: &Matrix4<f32>, p: Point) -> Option<([f64; 2], f32)> {
+    let p = [p.x as f32, p.y as f32, p.z as f32, 1.0];
+    let p = vecmath::col_mat4_transform(*mvp, p);
+    if p[2] < 0.0 {return None};
+    let p = vecmath::vec4_scale(p, 1.0/p[3]);
+    Some(([p[0] as f64, p[1] as f64], p[2]))
+}
+
+/// Sorts projected points from far to near.
+pub fn sort_by_depth(points: &mut [Projected]) {
+    points.sort_by(|a, b| b.depth.partial_cmp(&a.depth).unwrap_or(std::cmp::Ordering::Equal));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_styled() {
+        let mut r = Renderer::new();
+        let red = [1.0, 0.0, 0.0, 1.0];
+        r.sample_styled(&Point::circle(), 4, red, 0.05);
+        r.sample(&Point::x(), 2);
+        assert_eq!(r.points.len(), 6);
+        for &(_, style) in &r.points[..4] {
+            assert_eq!(style, Style {color: red, radius: 0.05});
+        }
+        assert_eq!(r.points[4].1, Style::default());
+        assert_eq!(r.points[1].0, Point::circle().call(0.25));
+    }
+
+    #[test]
+    fn depth_sorting() {
+        let style = Style::default();
+        let mut points: Vec<Projected> = [0.5, 0.9, 0.1, 0.7].iter()
+            .map(|&depth| Projected {pos: [0.0, 0.0], depth, style})
+            .collect();
+        sort_by_depth(&mut points);
+        let depths: Vec<f32> = points.iter().map(|p| p.depth).collect();
+        assert_eq!(depths, vec![0.9, 0.7, 0.5, 0.1]);
+
+        let mut r = Renderer::new();
+        r.sample(&Point::x(), 2);
+        let projected = r.project(&vecmath::mat4_id());
+        assert_eq!(projected.len(), 2);
+        assert_eq!(projected[1].pos, [0.5, 0.0]);
+    }
+
+    #[test]
+    fn polyline() {
+        let mut r = Renderer::new();
+        r.sample_polyline(&Point::x(), 4);
+        assert_eq!(r.segments.len(), 4);
+        assert!(r.points.is_empty());
+        for (i, &([a, b], _)) in r.segments.iter().enumerate() {
+            assert_eq!(a, Point::new(i as f64 / 4.0, 0.0, 0.0));
+            assert_eq!(b, Point::new((i + 1) as f64 / 4.0, 0.0, 0.0));
+        }
+    }
+}
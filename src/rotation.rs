@@ -0,0 +1,224 @@
+//! Rotation and transform helpers.
+
+use super::*;
+
+/// Quaternion for representing rotations.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Quaternion {
+    /// Scalar part.
+    pub w: f64,
+    /// Vector part.
+    pub v: Point,
+}
+
+impl Quaternion {
+    /// Returns the identity rotation.
+    pub fn identity() -> Quaternion {
+        Quaternion {w: 1.0, v: Point::origin()}
+    }
+
+    /// Creates a rotation of `angle` radians around an axis.
+    ///
+    /// The axis does not need to be normalized.
+    pub fn from_axis_angle(axis: Point, angle: f64) -> Quaternion {
+        let half = 0.5 * angle;
+        Quaternion {
+            w: half.cos(),
+            v: axis * (half.sin() / axis.norm()),
+        }
+    }
+
+    /// Returns the conjugate, which is the inverse rotation for unit quaternions.
+    pub fn conjugate(self) -> Quaternion {
+        Quaternion {w: self.w, v: self.v * -1.0}
+    }
+
+    /// Rotates a point.
+    pub fn rotate(self, p: Point) -> Point {
+        let t = self.v.cross(p) * 2.0;
+        p + t * self.w + self.v.cross(t)
+    }
+}
+
+impl Mul for Quaternion {
+    type Output = Quaternion;
+
+    /// Composes two rotations, such that `other` is applied first.
+    fn mul(self, other: Quaternion) -> Quaternion {
+        Quaternion {
+            w: self.w * other.w - self.v.dot(other.v),
+            v: other.v * self.w + self.v * other.w + self.v.cross(other.v),
+        }
+    }
+}
+
+/// Creates a spherical linear interpolation between two directions over `[0, 1]`.
+///
+/// The directions are normalized.
+/// Nearly parallel directions fall back to normalized linear interpolation.
+/// The result is undefined for opposite directions.
+pub fn slerp(a: Point, b: Point) -> PointFunc<f64> {
+    let a = a.normalize();
+    let b = b.normalize();
+    let omega = a.angle_between(b);
+    let s = omega.sin();
+    if s < 0.000001 {
+        let p: PointFunc<f64> = line(&a, &b, &id());
+        let n = p.clone().norm();
+        p / n
+    } else {
+        let wa: Func<f64, f64> = Arc::new(move |t| ((1.0 - t) * omega).sin() / s);
+        let wb: Func<f64, f64> = Arc::new(move |t| (t * omega).sin() / s);
+        a * wa + b * wb
+    }
+}
+
+/// Creates a surface of revolution by rotating a profile curve around the z-axis.
+///
+/// The profile is usually in the xz-plane.
+/// The first parameter moves along the profile and the second rotates around the axis.
+pub fn revolve(profile: &PointFunc<f64>) -> PointFunc<[f64; 2]> {
+    let profile = profile.clone();
+    let f = Arc::new(move |p: [f64; 2]| {
+        let q = Quaternion::from_axis_angle(Point::new(0.0, 0.0, 1.0), p[1] * TAU);
+        q.rotate(profile.call(p[0]))
+    });
+    let fx = f.clone();
+    let fy = f.clone();
+    let fz = f;
+    Point {
+        x: Arc::new(move |p| fx(p).x),
+        y: Arc::new(move |p| fy(p).y),
+        z: Arc::new(move |p| fz(p).z),
+    }
+}
+
+/// Affine transform of rotation, uniform scale and translation.
+///
+/// Points are first scaled, then rotated and then translated.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Affine {
+    /// Rotation as unit quaternion.
+    pub rotation: Quaternion,
+    /// Uniform scale.
+    pub scale: f64,
+    /// Translation.
+    pub translation: Point,
+}
+
+impl Affine {
+    /// Returns the identity transform.
+    pub fn identity() -> Affine {
+        Affine {
+            rotation: Quaternion::identity(),
+            scale: 1.0,
+            translation: Point::origin(),
+        }
+    }
+
+    /// Transforms a point.
+    pub fn apply(&self, p: Point) -> Point {
+        self.rotation.rotate(p * self.scale) + self.translation
+    }
+
+    /// Returns the transform that applies `other` first and then `self`.
+    pub fn compose(&self, other: &Affine) -> Affine {
+        Affine {
+            rotation: self.rotation * other.rotation,
+            scale: self.scale * other.scale,
+            translation: self.apply(other.translation),
+        }
+    }
+
+    /// Returns the inverse transform.
+    pub fn inverse(&self) -> Affine {
+        let rotation = self.rotation.conjugate();
+        let scale = 1.0 / self.scale;
+        Affine {
+            rotation,
+            scale,
+            translation: rotation.rotate(self.translation) * -scale,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotate() {
+        let z = Point::new(0.0, 0.0, 1.0);
+        let a = Quaternion::from_axis_angle(z, 0.25 * TAU);
+        let ex = Point::new(1.0, 0.0, 0.0);
+        assert!(a.rotate(ex).approx_eq(&Point::new(0.0, 1.0, 0.0), 1e-12));
+        assert!(a.rotate(z).approx_eq(&z, 1e-12));
+
+        let b = a * a;
+        assert!(b.rotate(ex).approx_eq(&Point::new(-1.0, 0.0, 0.0), 1e-12));
+
+        let c = Quaternion::from_axis_angle(Point::new(1.0, 0.0, 0.0), 0.25 * TAU);
+        // Rotate around z first, then around x.
+        let d = c * a;
+        assert!(d.rotate(ex).approx_eq(&Point::new(0.0, 0.0, 1.0), 1e-12));
+        assert_eq!(Quaternion::identity().rotate(ex), ex);
+    }
+
+    #[test]
+    fn test_slerp() {
+        let ex = Point::new(1.0, 0.0, 0.0);
+        let ey = Point::new(0.0, 1.0, 0.0);
+        let a = slerp(ex, ey * 2.0);
+        assert!(a.call(0.0).approx_eq(&ex, 1e-12));
+        assert!(a.call(1.0).approx_eq(&ey, 1e-12));
+        assert!(a.call(0.5).approx_eq(&(ex + ey).normalize(), 1e-12));
+        assert!((a.call(0.3).norm() - 1.0).abs() < 1e-12);
+
+        let b = slerp(ex, ex * 3.0);
+        assert!(b.call(0.5).approx_eq(&ex, 1e-12));
+    }
+
+    #[test]
+    fn test_revolve() {
+        let profile = Point::z() + [1.0, 0.0, 0.0];
+        let a = revolve(&profile);
+        for i in 0..=4 {
+            for j in 0..8 {
+                let u = i as f64 / 4.0;
+                let v = j as f64 / 8.0;
+                let p = a.call([u, v]);
+                let q = Point::new((v * TAU).cos(), (v * TAU).sin(), u);
+                assert!(p.approx_eq(&q, 1e-12));
+            }
+        }
+    }
+
+    #[test]
+    fn affine() {
+        let a = Affine {
+            rotation: Quaternion::from_axis_angle(Point::new(1.0, 2.0, 3.0), 0.7),
+            scale: 2.5,
+            translation: Point::new(-1.0, 0.5, 4.0),
+        };
+        let b = Affine {
+            rotation: Quaternion::from_axis_angle(Point::new(0.0, 0.0, 1.0), 0.25 * TAU),
+            scale: 0.5,
+            translation: Point::new(1.0, 0.0, 0.0),
+        };
+        let points = [
+            Point::origin(),
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(-2.0, 3.0, 0.5),
+        ];
+        let ident = a.compose(&a.inverse());
+        let ident2 = a.inverse().compose(&a);
+        let ab = a.compose(&b);
+        for &p in &points {
+            assert!(ident.apply(p).approx_eq(&p, 1e-12));
+            assert!(ident2.apply(p).approx_eq(&p, 1e-12));
+            assert!(ab.apply(p).approx_eq(&a.apply(b.apply(p)), 1e-12));
+            assert_eq!(Affine::identity().apply(p), p);
+        }
+        assert!(b.apply(Point::new(2.0, 0.0, 0.0)).approx_eq(&Point::new(1.0, 1.0, 0.0), 1e-12));
+    }
+}
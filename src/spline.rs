@@ -0,0 +1,111 @@
+//! Interpolating splines through points.
+
+use super::*;
+
+/// Creates a closed C2 cubic spline through points.
+///
+/// The curve passes through point `i` of `m` at parameter `i / m`
+/// and wraps periodically, such that parameter `1` returns to the first point.
+/// Returns the origin for no points and a constant for a single point.
+pub fn periodic_spline(points: &[Point]) -> PointFunc<f64> {
+    let m = points.len();
+    match m {
+        0 => return PointFunc::constant(Point::origin()),
+        1 => return PointFunc::constant(points[0]),
+        _ => {}
+    }
+    let ps = points.to_vec();
+    let rhs: Vec<Point> = (0..m)
+        .map(|i| (ps[(i + 1) % m] - ps[i] * 2.0 + ps[(i + m - 1) % m]) * 6.0)
+        .collect();
+    // Second derivatives at the points.
+    let ms = solve_cyclic(&rhs);
+    PointFunc::from_fn(move |t: f64| {
+        let s = t.rem_euclid(1.0) * m as f64;
+        let i = (s.floor() as usize).min(m - 1);
+        let j = (i + 1) % m;
+        let u = s - i as f64;
+        let v = 1.0 - u;
+        ps[i] * v + ps[j] * u +
+        (ms[i] * (v * v * v - v) + ms[j] * (u * u * u - u)) * (1.0 / 6.0)
+    })
+}
+
+/// Solves a cyclic system with 4 on the diagonal and 1 elsewhere in the band.
+fn solve_cyclic(rhs: &[Point]) -> Vec<Point> {
+    let m = rhs.len();
+    if m == 2 {
+        // Both neighbours are the same point, giving `4 x0 + 2 x1 = r0` and `2 x0 + 4 x1 = r1`.
+        let x0 = (rhs[0] * 4.0 - rhs[1] * 2.0) * (1.0 / 12.0);
+        let x1 = (rhs[1] * 4.0 - rhs[0] * 2.0) * (1.0 / 12.0);
+        return vec![x0, x1];
+    }
+    // Sherman-Morrison correction of the corner elements.
+    let gamma = -4.0;
+    let mut diag = vec![4.0; m];
+    diag[0] -= gamma;
+    diag[m - 1] -= 1.0 / gamma;
+    let x = solve_tridiagonal(&diag, rhs);
+    let mut u = vec![0.0; m];
+    u[0] = gamma;
+    u[m - 1] = 1.0;
+    let z = solve_tridiagonal(&diag, &u);
+    let fact = (x[0] + x[m - 1] * (1.0 / gamma)) * (1.0 / (1.0 + z[0] + z[m - 1] / gamma));
+    x.iter().zip(&z).map(|(&xi, &zi)| xi - fact * zi).collect()
+}
+
+/// Solves a tridiagonal system with 1 on the off-diagonals using the Thomas algorithm.
+fn solve_tridiagonal<T>(diag: &[f64], rhs: &[T]) -> Vec<T>
+    where T: Copy + Sub<Output = T> + Mul<f64, Output = T>
+{
+    let n = rhs.len();
+    let mut c = vec![0.0; n];
+    let mut d = Vec::with_capacity(n);
+    c[0] = 1.0 / diag[0];
+    d.push(rhs[0] * c[0]);
+    for i in 1..n {
+        let w = 1.0 / (diag[i] - c[i - 1]);
+        c[i] = w;
+        let di = (rhs[i] - d[i - 1]) * w;
+        d.push(di);
+    }
+    for i in (0..n - 1).rev() {
+        d[i] = d[i] - d[i + 1] * c[i];
+    }
+    d
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_periodic_spline() {
+        let points = [
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(0.0, 2.0, 1.0),
+            Point::new(-1.0, 0.0, 0.0),
+            Point::new(0.0, -1.0, -1.0),
+            Point::new(0.5, -0.5, 0.0),
+        ];
+        let a = periodic_spline(&points);
+        for (i, p) in points.iter().enumerate() {
+            assert!(a.call(i as f64 / 5.0).approx_eq(p, 1e-12));
+        }
+        // Wraps continuously with matching first and second derivatives.
+        let eps = 1e-7;
+        assert!(a.call(1.0).approx_eq(&points[0], 1e-12));
+        let d_before = (a.call(0.0) - a.call(-eps)) * (1.0 / eps);
+        let d_after = (a.call(eps) - a.call(0.0)) * (1.0 / eps);
+        assert!(d_before.approx_eq(&d_after, 1e-3));
+        let eps = 1e-4;
+        let dd_before = (a.call(-2.0 * eps) - a.call(-eps) * 2.0 + a.call(0.0)) * (1.0 / (eps * eps));
+        let dd_after = (a.call(0.0) - a.call(eps) * 2.0 + a.call(2.0 * eps)) * (1.0 / (eps * eps));
+        assert!(dd_before.approx_eq(&dd_after, 1.0));
+
+        let b = periodic_spline(&points[..2]);
+        assert!(b.call(0.0).approx_eq(&points[0], 1e-12));
+        assert!(b.call(0.5).approx_eq(&points[1], 1e-12));
+        assert_eq!(periodic_spline(&points[..1]).call(0.3), points[0]);
+    }
+}